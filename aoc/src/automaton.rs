@@ -0,0 +1,294 @@
+//! Rule based evolution of a [`Grid`], one generation at a time.
+//!
+//! Two modes cover the usual puzzles: every cell computing its next value from its
+//! neighborhood (Game of Life), and agents proposing a move that only happens when nobody
+//! else wants the same spot (the elves spreading out).
+
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display},
+};
+
+use num::PrimInt;
+
+use crate::grid::{Coordinate, Grid, Neighborhood};
+
+/// What a rule gets to see of a single cell
+#[derive(Debug)]
+pub struct Cell<'a, T: Debug, N = i32> {
+    pub coord: Coordinate<N>,
+    pub value: &'a T,
+    /// The whole grid as it was at the start of the generation
+    pub grid: &'a Grid<T, N>,
+    /// Number of generations run before this one
+    pub generation: usize,
+    neighborhood: Neighborhood,
+}
+
+impl<'a, T: Debug, N: PrimInt + Debug + Display> Cell<'a, T, N> {
+    /// Adjacent cells in the automaton's neighborhood, cells outside the grid read as empty
+    pub fn neighbors(&self) -> impl Iterator<Item = (Coordinate<N>, &'a T)> + '_ {
+        self.neighborhood
+            .neighbors(self.coord)
+            .into_iter()
+            .map(|c| (c, self.grid.get(c)))
+    }
+
+    /// Number of adjacent cells matching `predicate`
+    pub fn count<F>(&self, predicate: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.neighbors().filter(|(_, v)| predicate(v)).count()
+    }
+}
+
+/// Resolution for [`Automaton::step_moves`] where a move only happens if a single agent
+/// proposed it
+pub fn unique<N: Copy>(_target: Coordinate<N>, agents: &[Coordinate<N>]) -> Option<Coordinate<N>> {
+    match agents {
+        [agent] => Some(*agent),
+        _ => None,
+    }
+}
+
+/// A grid evolving one generation at a time.
+///
+/// Rules read the previous generation and write into a second buffer, so cells never see
+/// values from the generation being computed. With growth enabled the grid gets one extra
+/// cell on every side before each generation and is shrunk back to its occupied cells after.
+#[derive(Debug)]
+pub struct Automaton<T: Debug, N = i32> {
+    grid: Grid<T, N>,
+    back: Option<Grid<T, N>>,
+    neighborhood: Neighborhood,
+    growth: bool,
+    generation: usize,
+}
+
+impl<T: Debug + Clone + PartialEq, N: PrimInt + Debug + Display> Automaton<T, N> {
+    pub fn new(grid: Grid<T, N>, neighborhood: Neighborhood) -> Self {
+        Self {
+            grid,
+            back: None,
+            neighborhood,
+            growth: false,
+            generation: 0,
+        }
+    }
+
+    /// Let the grid grow past its bounds as cells come alive or agents move out
+    pub fn unbounded(mut self) -> Self {
+        self.growth = true;
+        self
+    }
+
+    pub fn grid(&self) -> &Grid<T, N> {
+        &self.grid
+    }
+
+    pub fn into_grid(self) -> Grid<T, N> {
+        self.grid
+    }
+
+    /// Number of generations run so far
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn cell<'a>(&'a self, coord: Coordinate<N>, value: &'a T) -> Cell<'a, T, N> {
+        Cell {
+            coord,
+            value,
+            grid: &self.grid,
+            generation: self.generation,
+            neighborhood: self.neighborhood,
+        }
+    }
+
+    /// Runs one generation, every cell becomes what `rule` returns for it.
+    /// Returns whether any cell changed.
+    pub fn step<F>(&mut self, rule: F) -> bool
+    where
+        F: Fn(&Cell<T, N>) -> T,
+    {
+        if self.growth {
+            self.grid.pad(N::one());
+        }
+        let mut back = match self.back.take() {
+            Some(back) if back.bounds() == self.grid.bounds() => back,
+            _ => self.grid.clone(),
+        };
+
+        let mut changed = false;
+        for (coord, value) in &self.grid {
+            let next = rule(&self.cell(coord, value));
+            changed |= next != *value;
+            back[coord] = next;
+        }
+
+        self.back = Some(std::mem::replace(&mut self.grid, back));
+        if self.growth {
+            self.grid.shrink_to_fit();
+        }
+        self.generation += 1;
+
+        changed
+    }
+
+    /// Runs `generations` generations of [`Automaton::step`]
+    pub fn run<F>(&mut self, generations: usize, rule: F)
+    where
+        F: Fn(&Cell<T, N>) -> T,
+    {
+        for _ in 0..generations {
+            self.step(&rule);
+        }
+    }
+
+    /// Steps until a generation changes nothing and returns its number, counting from 1.
+    /// Never returns for a pattern that keeps changing.
+    pub fn run_until_stable<F>(&mut self, rule: F) -> usize
+    where
+        F: Fn(&Cell<T, N>) -> T,
+    {
+        while self.step(&rule) {}
+
+        self.generation
+    }
+
+    /// Runs one generation in two phases.
+    ///
+    /// Every cell that isn't empty proposes where to move with `propose`, then `resolve`
+    /// picks which of the agents that proposed the same target actually moves. Targets should
+    /// be empty cells. Returns the number of agents that moved.
+    pub fn step_moves<F, R>(&mut self, propose: F, resolve: R) -> usize
+    where
+        F: Fn(&Cell<T, N>) -> Option<Coordinate<N>>,
+        R: Fn(Coordinate<N>, &[Coordinate<N>]) -> Option<Coordinate<N>>,
+    {
+        let empty = self.grid.empty().clone();
+        let mut proposals: BTreeMap<Coordinate<N>, Vec<Coordinate<N>>> = BTreeMap::new();
+        for (coord, value) in &self.grid {
+            if *value == empty {
+                continue;
+            }
+            if let Some(target) = propose(&self.cell(coord, value)) {
+                if self.growth || self.grid.get_bounded(target).is_ok() {
+                    proposals.entry(target).or_default().push(coord);
+                }
+            }
+        }
+
+        let moves: Vec<_> = proposals
+            .iter()
+            .filter_map(|(target, agents)| Some((resolve(*target, agents)?, *target)))
+            .collect();
+        // Lift every agent first, so one can move into a spot another just left
+        let agents: Vec<T> = moves
+            .iter()
+            .map(|(from, _)| std::mem::replace(&mut self.grid[*from], empty.clone()))
+            .collect();
+        for ((_, to), agent) in moves.iter().zip(agents) {
+            self.grid.set_resize(*to, agent);
+        }
+
+        if self.growth {
+            self.grid.shrink_to_fit();
+        }
+        self.generation += 1;
+
+        moves.len()
+    }
+
+    /// Steps until no agent moves and returns the number of that generation, counting from 1
+    pub fn run_moves_until_stable<F, R>(&mut self, propose: F, resolve: R) -> usize
+    where
+        F: Fn(&Cell<T, N>) -> Option<Coordinate<N>>,
+        R: Fn(Coordinate<N>, &[Coordinate<N>]) -> Option<Coordinate<N>>,
+    {
+        while self.step_moves(&propose, &resolve) > 0 {}
+
+        self.generation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Rect;
+
+    fn life(cell: &Cell<char>) -> char {
+        let alive = cell.count(|c| *c == '#');
+        match (*cell.value, alive) {
+            ('#', 2 | 3) | ('.', 3) => '#',
+            _ => '.',
+        }
+    }
+
+    fn parse(input: &str) -> Grid<char> {
+        Grid::parse(input, '.', &[], |c| c).unwrap().0
+    }
+
+    #[test]
+    fn test_blinker() {
+        let start = parse(".....\n.....\n.###.\n.....\n.....");
+        let mut automaton = Automaton::new(start.clone(), Neighborhood::Eight);
+
+        assert!(automaton.step(life));
+        assert_eq!(
+            automaton.grid().to_string(),
+            ".....\n..#..\n..#..\n..#..\n.....\n"
+        );
+        automaton.run(3, life);
+        assert_eq!(automaton.generation(), 4);
+        assert_eq!(automaton.grid().to_string(), start.to_string());
+    }
+
+    #[test]
+    fn test_stable() {
+        let mut automaton = Automaton::new(parse("##.\n#..\n..."), Neighborhood::Eight);
+        assert_eq!(automaton.run_until_stable(life), 2);
+        assert_eq!(automaton.grid().to_string(), "##.\n##.\n...\n");
+    }
+
+    #[test]
+    fn test_unbounded_glider() {
+        let mut automaton = Automaton::new(parse(".#.\n..#\n###"), Neighborhood::Eight).unbounded();
+        automaton.run(4, life);
+
+        let grid = automaton.into_grid();
+        assert_eq!(grid.bounds(), Rect::new((1, 1).into(), (3, 3).into()));
+        assert_eq!(grid.to_string(), ".#.\n..#\n###\n");
+    }
+
+    #[test]
+    fn test_moves() {
+        // The small example of 2022 day 23, y grows downwards so north is -y
+        let elves = parse(".....\n..##.\n..#..\n.....\n..##.\n.....");
+        let sides: [[(i32, i32); 3]; 4] = [
+            [(0, -1), (1, -1), (-1, -1)],
+            [(0, 1), (1, 1), (-1, 1)],
+            [(-1, 0), (-1, -1), (-1, 1)],
+            [(1, 0), (1, -1), (1, 1)],
+        ];
+        let propose = |cell: &Cell<char>| {
+            let free = |(dx, dy): (i32, i32)| *cell.grid.get(cell.coord.offset(dx, dy)) == '.';
+            if cell.count(|c| *c == '#') == 0 {
+                return None;
+            }
+            (0..4)
+                .map(|i| sides[(cell.generation + i) % 4])
+                .find(|side| side.iter().all(|d| free(*d)))
+                .map(|side| cell.coord.offset(side[0].0, side[0].1))
+        };
+
+        let mut automaton = Automaton::new(elves, Neighborhood::Eight).unbounded();
+        assert_eq!(automaton.step_moves(propose, unique), 3);
+        assert_eq!(automaton.run_moves_until_stable(propose, unique), 4);
+        assert_eq!(
+            automaton.grid().to_string(),
+            "..#..\n....#\n#....\n....#\n.....\n..#..\n"
+        );
+    }
+}
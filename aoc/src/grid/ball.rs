@@ -0,0 +1,153 @@
+use std::ops::RangeInclusive;
+
+use num::{PrimInt, Signed};
+
+use super::{
+    coordinate::{abs_diff, wide},
+    Coordinate,
+};
+
+/// Every coordinate within `radius` manhattan distance of `center`, a diamond on the grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ManhattanBall<N = i32> {
+    pub center: Coordinate<N>,
+    pub radius: N,
+}
+
+impl<N: PrimInt + Signed> ManhattanBall<N> {
+    pub fn new(center: Coordinate<N>, radius: N) -> Self {
+        Self { center, radius }
+    }
+
+    /// Ball around `center` reaching exactly up to `edge`
+    pub fn through(center: Coordinate<N>, edge: Coordinate<N>) -> Self {
+        let radius = N::from(center.manhattan_distance(edge));
+        Self::new(center, radius.expect("radius doesn't fit the coordinates"))
+    }
+
+    /// Same center, radius grown by `amount`
+    pub fn expanded(self, amount: N) -> Self {
+        Self::new(self.center, self.radius + amount)
+    }
+
+    pub fn contains(&self, coord: Coordinate<N>) -> bool {
+        self.radius
+            .to_u128()
+            .is_some_and(|radius| self.center.manhattan_distance(coord) <= radius)
+    }
+
+    /// Range of x values covered on row `y`, `None` if the ball doesn't reach it
+    pub fn row(&self, y: N) -> Option<RangeInclusive<N>> {
+        let rest = wide(self.radius) - i128::try_from(abs_diff(self.center.y, y)).ok()?;
+        let rest = N::from(rest).filter(|rest| *rest >= N::zero())?;
+
+        Some((self.center.x - rest)..=(self.center.x + rest))
+    }
+
+    /// Every covered coordinate, row by row
+    pub fn coords(self) -> impl Iterator<Item = Coordinate<N>> {
+        num::range_inclusive(self.center.y - self.radius, self.center.y + self.radius).flat_map(
+            move |y| {
+                let (start, end) = self.row(y).unwrap().into_inner();
+                num::range_inclusive(start, end).map(move |x| Coordinate::new(x, y))
+            },
+        )
+    }
+
+    /// Coordinates at exactly `radius + 1`, the ring just outside the ball
+    pub fn perimeter(self) -> impl Iterator<Item = Coordinate<N>> {
+        let r = self.radius + N::one();
+        let Coordinate { x, y } = self.center;
+        num::range(N::zero(), r).flat_map(move |i| {
+            [
+                Coordinate::new(x + i, y + r - i),
+                Coordinate::new(x + r - i, y - i),
+                Coordinate::new(x - i, y - r + i),
+                Coordinate::new(x - r + i, y + i),
+            ]
+        })
+    }
+
+    /// Coordinates lying on the edge of both diamonds.
+    ///
+    /// Only points where a rising edge of one crosses a falling edge of the other are found,
+    /// edges running along each other are left out.
+    pub fn edge_intersections(&self, other: &Self) -> Vec<Coordinate<N>> {
+        // Edges lie on lines x + y = rising and x - y = falling
+        let rising = |b: &Self| {
+            [
+                b.center.x + b.center.y - b.radius,
+                b.center.x + b.center.y + b.radius,
+            ]
+        };
+        let falling = |b: &Self| {
+            [
+                b.center.x - b.center.y - b.radius,
+                b.center.x - b.center.y + b.radius,
+            ]
+        };
+        let two = N::one() + N::one();
+
+        let mut points = Vec::new();
+        for (a, b) in [(self, other), (other, self)] {
+            for p in rising(a) {
+                for q in falling(b) {
+                    if (p + q) % two != N::zero() {
+                        continue;
+                    }
+                    let point = Coordinate::new((p + q) / two, (p - q) / two);
+                    let on_edge = |ball: &Self| {
+                        Some(ball.center.manhattan_distance(point)) == ball.radius.to_u128()
+                    };
+                    if on_edge(self) && on_edge(other) && !points.contains(&point) {
+                        points.push(point);
+                    }
+                }
+            }
+        }
+
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains_and_rows() {
+        let ball = ManhattanBall::new(Coordinate::new(8, 7), 9);
+        assert!(ball.contains((8, -2).into()));
+        assert!(ball.contains((2, 10).into()));
+        assert!(!ball.contains((2, 11).into()));
+        assert_eq!(ball.row(10), Some(2..=14));
+        assert_eq!(ball.row(16), Some(8..=8));
+        assert_eq!(ball.row(17), None);
+        assert_eq!(ball.coords().count(), 2 * 9 * 9 + 2 * 9 + 1);
+        assert!(ball.coords().all(|c| ball.contains(c)));
+    }
+
+    #[test]
+    fn test_perimeter() {
+        let ball = ManhattanBall::new(Coordinate::new(0, 0), 2);
+        let ring: Vec<_> = ball.perimeter().collect();
+        assert_eq!(ring.len(), 12);
+        assert!(ring.iter().all(|c| c.manhattan_distance(ball.center) == 3));
+        let mut unique = ring.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), ring.len());
+    }
+
+    #[test]
+    fn test_edge_intersections() {
+        let a = ManhattanBall::new(Coordinate::new(0, 0), 2);
+        let b = ManhattanBall::new(Coordinate::new(2, 0), 2);
+        let mut points = a.edge_intersections(&b);
+        points.sort();
+        assert_eq!(points, vec![(1, -1).into(), (1, 1).into()]);
+
+        let far = ManhattanBall::new(Coordinate::new(10, 10), 1);
+        assert!(a.edge_intersections(&far).is_empty());
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Sub},
+};
+
+use num::PrimInt;
+
+use super::{Direction8, DIRECTIONS, DIRECTIONS8};
+
+/// Abstract coordinate in a two dimensional plane.
+///
+/// Generic over the integer type of its axes, `i32` unless stated otherwise.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct Coordinate<N = i32> {
+    pub x: N,
+    pub y: N,
+}
+
+impl<N> Coordinate<N> {
+    pub const fn new(x: N, y: N) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<N: PrimInt> Coordinate<N> {
+    /// One step closer to `other` on both axes
+    pub fn closest(self, other: Self) -> Self {
+        Self {
+            x: step_towards(self.x, other.x),
+            y: step_towards(self.y, other.y),
+        }
+    }
+
+    pub fn offset(self, xd: N, yd: N) -> Self {
+        Self {
+            x: self.x + xd,
+            y: self.y + yd,
+        }
+    }
+
+    pub fn with_x(self, x: N) -> Self {
+        Self { x, y: self.y }
+    }
+
+    pub fn with_y(self, y: N) -> Self {
+        Self { x: self.x, y }
+    }
+
+    /// Moves `amount` steps towards `direction`, either a [`Direction`](super::Direction) or a
+    /// [`Direction8`].
+    ///
+    /// # Panics
+    ///
+    /// When the result doesn't fit in `N`, use [`Coordinate::checked_offset_direction`] for
+    /// unsigned coordinates that may walk off zero.
+    pub fn offset_direction(self, direction: impl Into<Direction8>, amount: N) -> Self {
+        self.checked_offset_direction(direction, amount)
+            .expect("coordinate overflow")
+    }
+
+    pub fn checked_offset_direction(
+        self,
+        direction: impl Into<Direction8>,
+        amount: N,
+    ) -> Option<Self> {
+        let step = direction.into().to_offset();
+        Some(Self {
+            x: checked_step(self.x, step.x, amount)?,
+            y: checked_step(self.y, step.y, amount)?,
+        })
+    }
+
+    /// The four orthogonally adjacent coordinates, skipping any that don't fit in `N`
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        DIRECTIONS
+            .into_iter()
+            .filter_map(move |dir| self.checked_offset_direction(dir, N::one()))
+    }
+
+    /// All eight adjacent coordinates, diagonals included, skipping any that don't fit in `N`
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        DIRECTIONS8
+            .into_iter()
+            .filter_map(move |dir| self.checked_offset_direction(dir, N::one()))
+    }
+
+    /// Distance walking along the axes, saturating at `u128::MAX` for the farthest `u128`
+    /// coordinates
+    pub fn manhattan_distance(self, other: Self) -> u128 {
+        abs_diff(self.x, other.x).saturating_add(abs_diff(self.y, other.y))
+    }
+
+    /// Converts to another integer width, `None` if either axis doesn't fit
+    pub fn try_cast<M: PrimInt>(self) -> Option<Coordinate<M>> {
+        Some(Coordinate {
+            x: M::from(self.x)?,
+            y: M::from(self.y)?,
+        })
+    }
+}
+
+fn step_towards<N: PrimInt>(from: N, to: N) -> N {
+    match from.cmp(&to) {
+        std::cmp::Ordering::Less => from + N::one(),
+        std::cmp::Ordering::Equal => from,
+        std::cmp::Ordering::Greater => from - N::one(),
+    }
+}
+
+fn checked_step<N: PrimInt>(from: N, sign: i32, amount: N) -> Option<N> {
+    match sign.signum() {
+        1 => from.checked_add(&amount),
+        -1 => from.checked_sub(&amount),
+        _ => Some(from),
+    }
+}
+
+/// Distance between `a` and `b`, counted wide enough for the full range of any `N`
+pub(super) fn abs_diff<N: PrimInt>(a: N, b: N) -> u128 {
+    match (a.to_i128(), b.to_i128()) {
+        (Some(a), Some(b)) => a.abs_diff(b),
+        // Only an unsigned N goes past i128, its difference can't overflow
+        _ => (a.max(b) - a.min(b)).to_u128().unwrap(),
+    }
+}
+
+/// Number of coordinates from `a` to `b`, both included
+///
+/// # Panics
+///
+/// When there are more than `usize` can count.
+pub(super) fn extent<N: PrimInt>(a: N, b: N) -> usize {
+    abs_diff(a, b)
+        .checked_add(1)
+        .and_then(|n| usize::try_from(n).ok())
+        .expect("more coordinates on an axis than usize can count")
+}
+
+/// `n` as an `i128`, wide enough for arithmetic between any two coordinates of `N`
+///
+/// # Panics
+///
+/// For a `u128` or `usize` beyond `i128::MAX`.
+pub(super) fn wide<N: PrimInt>(n: N) -> i128 {
+    n.to_i128().expect("coordinate doesn't fit in i128")
+}
+
+impl<N: Display> Display for Coordinate<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<N> From<(N, N)> for Coordinate<N> {
+    fn from((x, y): (N, N)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<N: PrimInt> Sub for Coordinate<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<N: PrimInt> Add for Coordinate<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+pub fn bounds<N: PrimInt>(coords: &[Coordinate<N>]) -> Option<(Coordinate<N>, Coordinate<N>)> {
+    let min_x = coords.iter().map(|c| c.x).min()?;
+    let min_y = coords.iter().map(|c| c.y).min()?;
+    let max_x = coords.iter().map(|c| c.x).max()?;
+    let max_y = coords.iter().map(|c| c.y).max()?;
+
+    Some(((min_x, min_y).into(), (max_x, max_y).into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Direction;
+
+    #[test]
+    fn test_neighbors() {
+        let coord = Coordinate::new(2, 3);
+        assert_eq!(coord.neighbors4().count(), 4);
+        assert_eq!(coord.neighbors8().count(), 8);
+        assert!(coord.neighbors4().all(|c| c.manhattan_distance(coord) == 1));
+        assert!(coord.neighbors8().any(|c| c == Coordinate::new(1, 4)));
+        assert_eq!(
+            coord.offset_direction(Direction8::DownLeft, 2),
+            Coordinate::new(0, 5)
+        );
+        assert_eq!(
+            coord.offset_direction(Direction::Up, 2),
+            Coordinate::new(2, 1)
+        );
+    }
+
+    #[test]
+    fn test_unsigned() {
+        let origin = Coordinate::<usize>::new(0, 0);
+        assert_eq!(origin.neighbors4().count(), 2);
+        assert_eq!(origin.neighbors8().count(), 3);
+        assert_eq!(origin.checked_offset_direction(Direction::Up, 1), None);
+        assert_eq!(
+            Coordinate::<u8>::new(3, 1).manhattan_distance(Coordinate::new(1, 4)),
+            5
+        );
+        assert_eq!(
+            Coordinate::<u8>::new(3, 1).closest(Coordinate::new(1, 4)),
+            Coordinate::new(2, 2)
+        );
+    }
+
+    #[test]
+    fn test_full_range() {
+        let (min, max) = (Coordinate::<i8>::new(-100, -100), Coordinate::new(100, 100));
+        assert_eq!(min.manhattan_distance(max), 400);
+        assert_eq!(abs_diff(i8::MIN, i8::MAX), 255);
+        assert_eq!(extent(i8::MIN, i8::MAX), 256);
+        assert_eq!(abs_diff(u128::MAX, 0), u128::MAX);
+        assert_eq!(
+            Coordinate::new(u128::MAX, u128::MAX).manhattan_distance(Coordinate::new(0, 0)),
+            u128::MAX
+        );
+    }
+
+    #[test]
+    fn test_try_cast() {
+        let big = Coordinate::<i64>::new(4_000_000_000, 2);
+        assert_eq!(big.try_cast::<i32>(), None);
+        assert_eq!(
+            big.try_cast::<u64>(),
+            Some(Coordinate::new(4_000_000_000, 2))
+        );
+        assert_eq!(Coordinate::new(-1, 2).try_cast::<usize>(), None);
+        assert_eq!(
+            Coordinate::new(1, 2).try_cast::<usize>(),
+            Some(Coordinate::new(1, 2))
+        );
+        assert_eq!(
+            bounds(&[Coordinate::<i64>::new(5, -1), Coordinate::new(-3, 7)]),
+            Some((Coordinate::new(-3, -1), Coordinate::new(5, 7)))
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use std::{fmt::Display, str::FromStr};
+
+use super::Coordinate;
+
+/// One of the four cardinal directions, as seen on screen.
+///
+/// Coordinates follow [`Grid::parse`](super::Grid::parse): x grows to the right and y grows
+/// downwards, so `Up` points towards negative y and an arrow read from a map moves the way it
+/// points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+pub const DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+impl Direction {
+    /// Quarter turn counter clockwise
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    /// Quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        self.turn_left().opposite()
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    /// Unit step in this direction
+    pub fn to_offset(self) -> Coordinate {
+        match self {
+            Direction::Up => Coordinate::new(0, -1),
+            Direction::Down => Coordinate::new(0, 1),
+            Direction::Left => Coordinate::new(-1, 0),
+            Direction::Right => Coordinate::new(1, 0),
+        }
+    }
+}
+
+/// One of the four cardinal or four diagonal directions, with y growing downwards like
+/// [`Direction`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+/// All eight directions, clockwise starting at `Up`
+pub const DIRECTIONS8: [Direction8; 8] = [
+    Direction8::Up,
+    Direction8::UpRight,
+    Direction8::Right,
+    Direction8::DownRight,
+    Direction8::Down,
+    Direction8::DownLeft,
+    Direction8::Left,
+    Direction8::UpLeft,
+];
+
+impl Direction8 {
+    fn turn(self, eighths: usize) -> Self {
+        let i = DIRECTIONS8.iter().position(|d| *d == self).unwrap();
+        DIRECTIONS8[(i + eighths) % 8]
+    }
+
+    /// Eighth of a turn counter clockwise
+    pub fn turn_left(self) -> Self {
+        self.turn(7)
+    }
+
+    /// Eighth of a turn clockwise
+    pub fn turn_right(self) -> Self {
+        self.turn(1)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !matches!(
+            self,
+            Direction8::Up | Direction8::Right | Direction8::Down | Direction8::Left
+        )
+    }
+
+    /// Unit step in this direction, diagonals move one on both axes
+    pub fn to_offset(self) -> Coordinate {
+        match self {
+            Direction8::Up => Coordinate::new(0, -1),
+            Direction8::UpRight => Coordinate::new(1, -1),
+            Direction8::Right => Coordinate::new(1, 0),
+            Direction8::DownRight => Coordinate::new(1, 1),
+            Direction8::Down => Coordinate::new(0, 1),
+            Direction8::DownLeft => Coordinate::new(-1, 1),
+            Direction8::Left => Coordinate::new(-1, 0),
+            Direction8::UpLeft => Coordinate::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+            Direction::Right => Direction8::Right,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(String);
+
+impl std::error::Error for ParseDirectionError {}
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not a direction", self.0)
+    }
+}
+
+/// Accepts `U/D/L/R`, `^v<>` and `N/S/E/W`
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | '^' | 'N' => Ok(Direction::Up),
+            'D' | 'v' | 'S' => Ok(Direction::Down),
+            'L' | '<' | 'W' => Ok(Direction::Left),
+            'R' | '>' | 'E' => Ok(Direction::Right),
+            _ => Err(ParseDirectionError(c.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => c.try_into(),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
+
+/// Accepts everything [`Direction`] does plus the diagonals `NE/SE/SW/NW`
+impl FromStr for Direction8 {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Direction8::UpRight),
+            "SE" => Ok(Direction8::DownRight),
+            "SW" => Ok(Direction8::DownLeft),
+            "NW" => Ok(Direction8::UpLeft),
+            _ => s.parse::<Direction>().map(Direction8::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for dir in DIRECTIONS {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_left().turn_left(), dir.opposite());
+            assert_eq!(
+                dir.to_offset() + dir.opposite().to_offset(),
+                Coordinate::default()
+            );
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+
+        for dir in DIRECTIONS8 {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.opposite().opposite(), dir);
+            assert_eq!(
+                dir.to_offset() + dir.opposite().to_offset(),
+                Coordinate::default()
+            );
+        }
+        assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+    }
+
+    #[test]
+    fn test_parse() {
+        for s in ["U", "^", "N"] {
+            assert_eq!(s.parse(), Ok(Direction::Up));
+        }
+        assert_eq!(Direction::try_from('v'), Ok(Direction::Down));
+        assert_eq!(Direction::try_from('<'), Ok(Direction::Left));
+        assert_eq!(Direction::try_from('E'), Ok(Direction::Right));
+        assert!(Direction::try_from('x').is_err());
+        assert!("UU".parse::<Direction>().is_err());
+
+        assert_eq!("NW".parse(), Ok(Direction8::UpLeft));
+        assert_eq!("S".parse(), Ok(Direction8::Down));
+    }
+
+    #[test]
+    fn test_parsed_arrows() {
+        use crate::grid::Grid;
+
+        let (grid, markers) =
+            Grid::<char>::parse("..#\n.>.\n.^.", '.', &['^', '>'], |c| c).unwrap();
+        let up = markers.get('^').unwrap();
+        let arrow = Direction::try_from(*grid.get(up)).unwrap();
+        assert_eq!(up.offset_direction(arrow, 1), Coordinate::new(1, 1));
+        assert_eq!(*grid.get(up.offset_direction(arrow, 1)), '>');
+
+        let right = up.offset_direction(arrow, 1);
+        let arrow = Direction::try_from(*grid.get(right)).unwrap();
+        let next = right.offset_direction(Direction8::from(arrow).turn_left(), 1);
+        assert_eq!(*grid.get(next), '#');
+    }
+}
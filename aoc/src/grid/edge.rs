@@ -0,0 +1,302 @@
+use std::{
+    fmt::{Debug, Display},
+    sync::Arc,
+};
+
+use num::PrimInt;
+
+use super::{coordinate::wide, Coordinate, Direction8, Grid, OutOfBounds, OverflowType};
+
+/// Closure deciding where a step off the grid from `from` towards a direction ends up,
+/// `None` blocks the step.
+///
+/// It's shared rather than boxed so a [`Grid`] stays cheap to clone, clones keep the same
+/// closure.
+pub type EdgeFn<T, N> =
+    Arc<dyn Fn(&Grid<T, N>, Coordinate<N>, Direction8) -> Option<Coordinate<N>> + Send + Sync>;
+
+/// What the movement helpers of a [`Grid`] do with steps that leave its bounds
+#[derive(Default, Clone)]
+pub enum EdgePolicy<T: Debug, N = i32> {
+    /// Fail with [`OutOfBounds`]
+    #[default]
+    Error,
+    /// Grow the grid to include the new coordinate
+    Resize,
+    /// Come back in on the opposite side, like on a torus
+    Wrap,
+    /// Stop at the last cell inside the bounds
+    Clamp,
+    /// Ask the closure, it's called once for every single step that leaves the grid. Landing
+    /// outside the grid fails with [`OutOfBounds`]
+    Custom(EdgeFn<T, N>),
+}
+
+impl<T: Debug, N> Debug for EdgePolicy<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EdgePolicy::Error => write!(f, "Error"),
+            EdgePolicy::Resize => write!(f, "Resize"),
+            EdgePolicy::Wrap => write!(f, "Wrap"),
+            EdgePolicy::Clamp => write!(f, "Clamp"),
+            EdgePolicy::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+impl<T: Debug, N: PrimInt + Debug + Display> Grid<T, N> {
+    /// Same grid with `policy` used by the movement helpers
+    pub fn with_edge(mut self, policy: EdgePolicy<T, N>) -> Self {
+        self.edge = policy;
+        self
+    }
+
+    pub fn set_edge(&mut self, policy: EdgePolicy<T, N>) {
+        self.edge = policy;
+    }
+
+    pub fn edge(&self) -> &EdgePolicy<T, N> {
+        &self.edge
+    }
+
+    /// Moves `amount` steps from `from` towards `direction`, handling the edges as told by
+    /// the grid's [`EdgePolicy`]
+    pub fn offset_direction(
+        &mut self,
+        from: Coordinate<N>,
+        direction: impl Into<Direction8>,
+        amount: N,
+    ) -> Result<Coordinate<N>, OutOfBounds<N>>
+    where
+        T: Clone,
+    {
+        let direction = direction.into();
+        if let EdgePolicy::Custom(_) = self.edge {
+            return self.walk_custom(from, direction, amount);
+        }
+
+        let target = target(from, direction, amount);
+        let (start, end) = (self.start, self.end);
+        match self.edge {
+            EdgePolicy::Error => self.bounded(target),
+            EdgePolicy::Resize => {
+                let coord = narrow(target.0)
+                    .zip(narrow(target.1))
+                    .map(|(x, y)| Coordinate::new(x, y))
+                    .ok_or_else(|| out_of_bounds(target, start, end))?;
+                self.check_and_resize(coord);
+
+                Ok(coord)
+            }
+            EdgePolicy::Wrap => {
+                let wrap = |t: i128, s: N, e: N| {
+                    let (s, e) = (wide(s), wide(e));
+                    narrow(s + (t - s).rem_euclid(e - s + 1)).unwrap()
+                };
+                Ok(Coordinate::new(
+                    wrap(target.0, start.x, end.x),
+                    wrap(target.1, start.y, end.y),
+                ))
+            }
+            EdgePolicy::Clamp => {
+                let clamp = |t: i128, s: N, e: N| narrow(t.clamp(wide(s), wide(e))).unwrap();
+                Ok(Coordinate::new(
+                    clamp(target.0, start.x, end.x),
+                    clamp(target.1, start.y, end.y),
+                ))
+            }
+            EdgePolicy::Custom(_) => unreachable!(),
+        }
+    }
+
+    /// Single step from `from` towards `direction`, see [`Grid::offset_direction`]
+    pub fn step(
+        &mut self,
+        from: Coordinate<N>,
+        direction: impl Into<Direction8>,
+    ) -> Result<Coordinate<N>, OutOfBounds<N>>
+    where
+        T: Clone,
+    {
+        self.offset_direction(from, direction, N::one())
+    }
+
+    fn walk_custom(
+        &self,
+        from: Coordinate<N>,
+        direction: Direction8,
+        amount: N,
+    ) -> Result<Coordinate<N>, OutOfBounds<N>> {
+        let EdgePolicy::Custom(f) = &self.edge else {
+            unreachable!()
+        };
+
+        let mut pos = from;
+        for _ in num::range(N::zero(), amount) {
+            pos = match self.bounded(target(pos, direction, N::one())) {
+                Ok(next) => next,
+                Err(e) => {
+                    let next = f(self, pos, direction).ok_or(e)?;
+                    self.check_bounds(next)?;
+                    next
+                }
+            };
+        }
+
+        Ok(pos)
+    }
+
+    /// `target` as a coordinate if it's inside the bounds
+    fn bounded(&self, target: (i128, i128)) -> Result<Coordinate<N>, OutOfBounds<N>> {
+        let err = out_of_bounds(target, self.start, self.end);
+        match (&err.x_overflow, &err.y_overflow) {
+            (OverflowType::None, OverflowType::None) => Ok(err.coord),
+            _ => Err(err),
+        }
+    }
+}
+
+/// Where the step lands, computed wide enough to never overflow
+fn target<N: PrimInt>(from: Coordinate<N>, direction: Direction8, amount: N) -> (i128, i128) {
+    let step = direction.to_offset();
+    let amount = wide(amount);
+
+    (
+        wide(from.x) + step.x as i128 * amount,
+        wide(from.y) + step.y as i128 * amount,
+    )
+}
+
+fn narrow<N: PrimInt>(n: i128) -> Option<N> {
+    N::from(n)
+}
+
+/// Error for `target`, the coordinate saturates at the limits of `N`
+fn out_of_bounds<N: PrimInt>(
+    target: (i128, i128),
+    start: Coordinate<N>,
+    end: Coordinate<N>,
+) -> OutOfBounds<N> {
+    let axis = |t: i128, s: N, e: N| {
+        let n = narrow(t).unwrap_or(if t < 0 {
+            N::min_value()
+        } else {
+            N::max_value()
+        });
+        let overflow = if t < wide(s) {
+            OverflowType::Smaller(s)
+        } else if t > wide(e) {
+            OverflowType::Larger(e)
+        } else {
+            OverflowType::None
+        };
+        (n, overflow)
+    };
+    let (x, x_overflow) = axis(target.0, start.x, end.x);
+    let (y, y_overflow) = axis(target.1, start.y, end.y);
+
+    OutOfBounds::new(Coordinate::new(x, y), x_overflow, y_overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Direction;
+
+    fn grid(policy: EdgePolicy<char>) -> Grid<char> {
+        Grid::from_coords((0, 0).into(), (3, 2).into(), '.').with_edge(policy)
+    }
+
+    #[test]
+    fn test_error_and_resize() {
+        let mut grid = grid(EdgePolicy::Error);
+        let start = Coordinate::new(1, 1);
+        assert_eq!(grid.step(start, Direction::Up), Ok((1, 0).into()));
+        assert!(grid.offset_direction(start, Direction::Up, 2).is_err());
+
+        grid.set_edge(EdgePolicy::Resize);
+        assert_eq!(
+            grid.offset_direction(start, Direction8::UpLeft, 2),
+            Ok((-1, -1).into())
+        );
+        assert_eq!((grid.width(), grid.height()), (5, 4));
+    }
+
+    #[test]
+    fn test_wrap_and_clamp() {
+        let mut grid = grid(EdgePolicy::Wrap);
+        let start = Coordinate::new(0, 0);
+        assert_eq!(grid.step(start, Direction::Left), Ok((3, 0).into()));
+        assert_eq!(grid.step(start, Direction8::UpLeft), Ok((3, 2).into()));
+        assert_eq!(
+            grid.offset_direction(start, Direction::Right, 9),
+            Ok((1, 0).into())
+        );
+
+        grid.set_edge(EdgePolicy::Clamp);
+        assert_eq!(
+            grid.offset_direction(start, Direction8::DownRight, 9),
+            Ok((3, 2).into())
+        );
+        assert_eq!(grid.step(start, Direction::Up), Ok(start));
+    }
+
+    #[test]
+    fn test_unsigned() {
+        let mut grid: Grid<char, u8> =
+            Grid::from_coords((0, 0).into(), (255, 1).into(), '.').with_edge(EdgePolicy::Wrap);
+        assert_eq!(
+            grid.step((0, 0).into(), Direction::Left),
+            Ok((255, 0).into())
+        );
+        assert_eq!(
+            grid.step((255, 0).into(), Direction::Right),
+            Ok((0, 0).into())
+        );
+
+        grid.set_edge(EdgePolicy::Error);
+        let err = grid.step((255, 0).into(), Direction::Right).unwrap_err();
+        assert_eq!(err.coord, (255, 0).into());
+        assert!(matches!(err.x_overflow, OverflowType::Larger(255)));
+    }
+
+    #[test]
+    fn test_custom() {
+        // Wrap to the first cell that isn't empty on the same row or column
+        let mut grid = grid(EdgePolicy::Custom(Arc::new(|grid, from, direction| {
+            let inside = |c: &Coordinate| grid.get_bounded(*c).is_ok();
+            let mut pos = from;
+            while let Some(next) = pos
+                .checked_offset_direction(direction.opposite(), 1)
+                .filter(inside)
+            {
+                pos = next;
+            }
+            while *grid.get(pos) == ' ' {
+                pos = pos.checked_offset_direction(direction, 1).filter(inside)?;
+            }
+            (*grid.get(pos) != '#').then_some(pos)
+        })));
+        grid.set((0, 0).into(), ' ');
+        grid.set((3, 1).into(), '#');
+
+        let start = Coordinate::new(3, 0);
+        assert_eq!(grid.step(start, Direction::Right), Ok((1, 0).into()));
+        assert_eq!(
+            grid.offset_direction(start, Direction::Right, 3),
+            Ok((3, 0).into())
+        );
+        assert!(grid.step((0, 1).into(), Direction::Left).is_err());
+        assert_eq!(format!("{:?}", grid.edge()), "Custom(..)");
+    }
+
+    #[test]
+    fn test_custom_out_of_bounds() {
+        let mut grid = grid(EdgePolicy::Custom(Arc::new(|_, from, direction| {
+            from.checked_offset_direction(direction, 2)
+        })));
+        let err = grid.step((3, 0).into(), Direction::Right).unwrap_err();
+        assert_eq!(err.coord, (5, 0).into());
+        assert!(matches!(err.x_overflow, OverflowType::Larger(3)));
+    }
+}
@@ -0,0 +1,330 @@
+use std::{
+    fmt::{Debug, Display},
+    io,
+    path::Path,
+};
+
+use num::PrimInt;
+
+use super::{Coordinate, Grid, Line, Rect};
+
+/// 8 bit RGB colour
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(255, 0, 0);
+    pub const GREEN: Rgb = Rgb(0, 255, 0);
+    pub const BLUE: Rgb = Rgb(0, 0, 255);
+
+    pub const fn gray(level: u8) -> Self {
+        Rgb(level, level, level)
+    }
+
+    /// Colour `t` of the way from `self` to `other`, `t` is clamped to `0.0..=1.0`
+    pub fn lerp(self, other: Rgb, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+
+    /// Perceived brightness, used for grayscale output
+    pub fn luma(self) -> u8 {
+        ((self.0 as u32 * 299 + self.1 as u32 * 587 + self.2 as u32 * 114) / 1000) as u8
+    }
+}
+
+/// Picture of a [`Grid`], one pixel per cell before scaling, built with [`Grid::image`].
+///
+/// Overlays are drawn in grid coordinates, so they line up whatever the scale.
+#[derive(Debug, Clone)]
+pub struct Image<N = i32> {
+    bounds: Rect<N>,
+    pixels: Vec<Rgb>,
+    scale: usize,
+}
+
+impl<T: Debug, N: PrimInt + Debug + Display> Grid<T, N> {
+    /// Paints every cell with the colour returned by `colour`
+    pub fn image<F>(&self, colour: F) -> Image<N>
+    where
+        F: Fn(&T) -> Rgb,
+    {
+        let bounds = self.bounds();
+        Image::from_pixels(
+            bounds,
+            bounds.coords().map(|c| colour(self.get(c))).collect(),
+        )
+    }
+}
+
+impl<N: PrimInt> Image<N> {
+    /// Image of `bounds` from pixels given row by row
+    pub(super) fn from_pixels(bounds: Rect<N>, pixels: Vec<Rgb>) -> Self {
+        Self {
+            bounds,
+            pixels,
+            scale: 1,
+        }
+    }
+
+    /// Width in pixels, scaling included
+    pub fn width(&self) -> usize {
+        self.bounds.width() * self.scale
+    }
+
+    /// Height in pixels, scaling included
+    pub fn height(&self) -> usize {
+        self.bounds.height() * self.scale
+    }
+
+    /// Draws every cell as a `scale` by `scale` square
+    ///
+    /// # Panics
+    ///
+    /// When `scale` is zero.
+    pub fn scaled(mut self, scale: usize) -> Self {
+        assert!(scale > 0, "image scale must be at least 1");
+        self.scale = scale;
+        self
+    }
+
+    /// Paints `coords` with `colour`, coordinates outside the grid are skipped
+    pub fn overlay<I>(mut self, coords: I, colour: Rgb) -> Self
+    where
+        I: IntoIterator<Item = Coordinate<N>>,
+    {
+        for coord in coords {
+            if let Some(i) = self.index(coord) {
+                self.pixels[i] = colour;
+            }
+        }
+        self
+    }
+
+    /// Paints every coordinate on `line` with `colour`
+    pub fn line(self, line: Line<N>, colour: Rgb) -> Self {
+        self.overlay(line.coords(), colour)
+    }
+
+    fn index(&self, coord: Coordinate<N>) -> Option<usize> {
+        if !self.bounds.contains(coord) {
+            return None;
+        }
+        let x = (coord.x - self.bounds.min.x).to_usize()?;
+        let y = (coord.y - self.bounds.min.y).to_usize()?;
+
+        Some(y * self.bounds.width() + x)
+    }
+
+    /// Pixels row by row with scaling applied
+    fn rows(&self) -> impl Iterator<Item = Vec<Rgb>> + '_ {
+        let width = self.bounds.width();
+        self.pixels.chunks(width).flat_map(move |row| {
+            let scaled: Vec<Rgb> = row
+                .iter()
+                .flat_map(|p| std::iter::repeat_n(*p, self.scale))
+                .collect();
+            std::iter::repeat_n(scaled, self.scale)
+        })
+    }
+
+    /// Binary (`P6`) PPM
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width(), self.height()).into_bytes();
+        for row in self.rows() {
+            out.extend(row.iter().flat_map(|p| [p.0, p.1, p.2]));
+        }
+        out
+    }
+
+    /// Binary (`P5`) PGM of the luma of every pixel
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let mut out = format!("P5\n{} {}\n255\n", self.width(), self.height()).into_bytes();
+        for row in self.rows() {
+            out.extend(row.iter().map(|p| p.luma()));
+        }
+        out
+    }
+
+    /// RGB PNG, the image data is stored uncompressed
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity((self.width() * 3 + 1) * self.height());
+        for row in self.rows() {
+            // Filter type 0, the row is stored as is
+            raw.push(0);
+            raw.extend(row.iter().flat_map(|p| [p.0, p.1, p.2]));
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend((self.width() as u32).to_be_bytes());
+        header.extend((self.height() as u32).to_be_bytes());
+        // 8 bit depth, truecolour, default compression, filter and no interlacing
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut out, b"IHDR", &header);
+        png_chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+
+    /// Writes the image to `path`, the format follows the extension: `ppm`, `pgm` or `png`
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let data = match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => self.to_ppm(),
+            Some("pgm") => self.to_pgm(),
+            Some("png") => self.to_png(),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("unknown image format: {}", path.display()),
+                ))
+            }
+        };
+
+        std::fs::write(path, data)
+    }
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// Zlib stream made of uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = u16::MAX as usize;
+
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(blocks.peek().is_none() as u8);
+        let len = block.len() as u16;
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        let mut grid = Grid::from_coords((-1, 0).into(), (1, 1).into(), 0);
+        grid.set((0, 0).into(), 9);
+        grid
+    }
+
+    fn colour(height: &u8) -> Rgb {
+        Rgb::BLACK.lerp(Rgb::WHITE, *height as f64 / 9.0)
+    }
+
+    #[test]
+    fn test_ppm() {
+        let image = grid()
+            .image(colour)
+            .overlay([Coordinate::new(1, 1)], Rgb::RED);
+        let mut expected = b"P6\n3 2\n255\n".to_vec();
+        expected.extend([0, 0, 0, 255, 255, 255, 0, 0, 0]);
+        expected.extend([0, 0, 0, 0, 0, 0, 255, 0, 0]);
+        assert_eq!(image.to_ppm(), expected);
+    }
+
+    #[test]
+    fn test_pgm_scaled() {
+        let image = grid()
+            .image(colour)
+            .line(Line::vertical(-1, 0, 1), Rgb::gray(100))
+            .scaled(2);
+        assert_eq!((image.width(), image.height()), (6, 4));
+
+        let mut expected = b"P5\n6 4\n255\n".to_vec();
+        for row in [[100, 255, 0], [100, 0, 0]] {
+            for _ in 0..2 {
+                expected.extend(row.iter().flat_map(|p| [*p, *p]));
+            }
+        }
+        assert_eq!(image.to_pgm(), expected);
+    }
+
+    #[test]
+    fn test_png() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+
+        let png = grid().image(colour).to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 3, 0, 0, 0, 2]);
+        assert_eq!(&png[png.len() - 8..], b"IEND\xae\x42\x60\x82");
+    }
+
+    #[test]
+    fn test_zlib_blocks() {
+        let data = vec![7; u16::MAX as usize + 10];
+        let stream = zlib_stored(&data);
+        // Header, two block headers, the data and the checksum
+        assert_eq!(stream.len(), 2 + 5 * 2 + data.len() + 4);
+        assert_eq!(stream[2], 0);
+        assert_eq!(stream[2 + 5 + u16::MAX as usize], 1);
+    }
+
+    #[test]
+    fn test_save() {
+        let path = |ext| {
+            std::env::temp_dir().join(format!("aoc_{}_image_test_save.{ext}", std::process::id()))
+        };
+        let image = grid().image(colour);
+        for ext in ["ppm", "pgm", "png"] {
+            let path = path(ext);
+            image.save(&path).unwrap();
+            assert!(std::fs::metadata(&path).unwrap().len() > 0);
+            std::fs::remove_file(&path).unwrap();
+        }
+        let bmp = path("bmp");
+        assert!(image.save(&bmp).is_err());
+        assert!(!bmp.exists());
+    }
+}
@@ -0,0 +1,258 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Index, IndexMut},
+};
+
+use num::PrimInt;
+
+use super::{
+    bounds,
+    storage::{cell_count, dense_coord, dense_index},
+    Coordinate, Grid, Storage,
+};
+
+/// Every cell of a grid with its coordinate, in reading order, see [`Grid::iter`]
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T: Debug, N = i32> {
+    grid: &'a Grid<T, N>,
+    next: usize,
+    len: usize,
+}
+
+impl<'a, T: Debug, N: PrimInt + Debug + Display> Iterator for Iter<'a, T, N> {
+    type Item = (Coordinate<N>, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.len {
+            return None;
+        }
+        let coord = dense_coord(self.grid.start, self.grid.width, self.next);
+        self.next += 1;
+
+        Some((coord, self.grid.get(coord)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len - self.next;
+        (len, Some(len))
+    }
+}
+
+impl<T: Debug, N: PrimInt + Debug + Display> ExactSizeIterator for Iter<'_, T, N> {}
+
+impl<T: Debug, N: PrimInt + Debug + Display> Grid<T, N> {
+    /// Every cell inside the bounds with its coordinate, in reading order
+    ///
+    /// # Panics
+    ///
+    /// When the grid has more cells than `usize` can count.
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter {
+            grid: self,
+            next: 0,
+            len: cell_count(self.width, self.height),
+        }
+    }
+
+    /// Mutable [`Grid::iter`], a sparse grid stores empty cells for any it didn't have
+    pub fn iter_mut(&mut self) -> std::vec::IntoIter<(Coordinate<N>, &mut T)>
+    where
+        T: Clone,
+    {
+        let bounds = self.bounds();
+        self.fill_sparse(bounds);
+
+        let cells: Vec<_> = match &mut self.points {
+            Storage::Sparse(points) => {
+                let mut cells: Vec<_> = points
+                    .iter_mut()
+                    .filter(|(c, _)| bounds.contains(**c))
+                    .map(|(c, v)| (*c, v))
+                    .collect();
+                cells.sort_by_key(|(c, _)| (c.y, c.x));
+                cells
+            }
+            Storage::Dense(points) => bounds.coords().zip(points.iter_mut()).collect(),
+        };
+
+        cells.into_iter()
+    }
+
+    /// Panics with the bounds error when `coord` is outside the grid
+    fn assert_in_bounds(&self, coord: Coordinate<N>) {
+        if let Err(e) = self.check_bounds(coord) {
+            panic!("{e}");
+        }
+    }
+}
+
+/// Panics when `coord` is out of bounds, like indexing a slice
+impl<T: Debug, N: PrimInt + Debug + Display> Index<Coordinate<N>> for Grid<T, N> {
+    type Output = T;
+
+    fn index(&self, coord: Coordinate<N>) -> &Self::Output {
+        self.assert_in_bounds(coord);
+        self.get(coord)
+    }
+}
+
+/// Panics when `coord` is out of bounds, a sparse grid stores an empty cell if it has none
+impl<T: Debug + Clone, N: PrimInt + Debug + Display> IndexMut<Coordinate<N>> for Grid<T, N> {
+    fn index_mut(&mut self, coord: Coordinate<N>) -> &mut Self::Output {
+        self.assert_in_bounds(coord);
+        match &mut self.points {
+            Storage::Sparse(points) => points.entry(coord).or_insert_with(|| self.empty.clone()),
+            Storage::Dense(points) => &mut points[dense_index(self.start, self.width, coord)],
+        }
+    }
+}
+
+impl<'a, T: Debug, N: PrimInt + Debug + Display> IntoIterator for &'a Grid<T, N> {
+    type Item = (Coordinate<N>, &'a T);
+    type IntoIter = Iter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Debug + Clone, N: PrimInt + Debug + Display> IntoIterator for &'a mut Grid<T, N> {
+    type Item = (Coordinate<N>, &'a mut T);
+    type IntoIter = std::vec::IntoIter<(Coordinate<N>, &'a mut T)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Every cell inside the bounds, in reading order, unset sparse cells come out empty
+impl<T: Debug + Clone, N: PrimInt + Debug + Display> IntoIterator for Grid<T, N> {
+    type Item = (Coordinate<N>, T);
+    type IntoIter = std::vec::IntoIter<(Coordinate<N>, T)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let bounds = self.bounds();
+        let cells: Vec<_> = match self.points {
+            Storage::Sparse(mut points) => bounds
+                .coords()
+                .map(|c| (c, points.remove(&c).unwrap_or_else(|| self.empty.clone())))
+                .collect(),
+            Storage::Dense(points) => bounds.coords().zip(points).collect(),
+        };
+
+        cells.into_iter()
+    }
+}
+
+/// Sparse grid just big enough for every coordinate, other cells hold `T::default()`.
+/// Without any cells it's a single empty cell at the origin.
+impl<T: Debug + Clone + Default, N: PrimInt + Debug + Display> FromIterator<(Coordinate<N>, T)>
+    for Grid<T, N>
+{
+    fn from_iter<I: IntoIterator<Item = (Coordinate<N>, T)>>(iter: I) -> Self {
+        let cells: Vec<_> = iter.into_iter().collect();
+        let coords: Vec<_> = cells.iter().map(|(c, _)| *c).collect();
+        let origin = Coordinate::new(N::zero(), N::zero());
+        let (min, max) = bounds(&coords).unwrap_or((origin, origin));
+
+        let mut grid = Grid::from_coords(min, max, T::default());
+        for (coord, val) in cells {
+            grid.set(coord, val);
+        }
+
+        grid
+    }
+}
+
+/// Sets every cell, growing the grid where needed
+impl<T: Debug + Clone, N: PrimInt + Debug + Display> Extend<(Coordinate<N>, T)> for Grid<T, N> {
+    fn extend<I: IntoIterator<Item = (Coordinate<N>, T)>>(&mut self, iter: I) {
+        for (coord, val) in iter {
+            self.set_resize(coord, val);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{for_each_storage, Rect, StorageKind};
+
+    const SPAN: ((i32, i32), (i32, i32)) = ((0, 0), (2, 1));
+    const CELLS: [((i32, i32), char); 2] = [((1, 0), 'a'), ((0, 1), 'b')];
+
+    #[test]
+    fn test_index() {
+        for_each_storage(SPAN, '.', &CELLS, |mut grid| {
+            assert_eq!(grid[Coordinate::new(1, 0)], 'a');
+            assert_eq!(grid[Coordinate::new(2, 1)], '.');
+
+            grid[Coordinate::new(2, 1)] = 'c';
+            assert_eq!(grid.to_string(), ".a.\nb.c\n");
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let grid = Grid::with_storage((0, 0).into(), (2, 1).into(), '.', StorageKind::Sparse);
+        let _ = grid[Coordinate::new(3, 0)];
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_mut_out_of_bounds() {
+        let mut grid = Grid::with_storage((0, 0).into(), (2, 1).into(), '.', StorageKind::Dense);
+        grid[Coordinate::new(0, -1)] = 'x';
+    }
+
+    #[test]
+    #[should_panic(expected = "grid has more cells than usize can count")]
+    fn test_iter_too_many_cells() {
+        let far = Coordinate::<i64>::new(5_000_000_000, 5_000_000_000);
+        let grid = Grid::from_coords(Coordinate::new(-far.x, -far.y), far, '.');
+        let _ = grid.iter();
+    }
+
+    #[test]
+    fn test_iterators() {
+        for_each_storage(SPAN, '.', &CELLS, |mut grid| {
+            let cells: String = grid.iter().map(|(_, c)| *c).collect();
+            assert_eq!(cells, ".a.b..");
+            assert_eq!(grid.iter().len(), 6);
+
+            for (coord, c) in &mut grid {
+                if coord.x == 2 {
+                    *c = '#';
+                }
+            }
+            let mut borrowed = Vec::new();
+            for (coord, c) in &grid {
+                borrowed.push((coord, *c));
+            }
+            assert_eq!(borrowed[2], ((2, 0).into(), '#'));
+
+            let owned: Vec<(Coordinate, char)> = grid.into_iter().collect();
+            assert_eq!(owned.len(), 6);
+            assert_eq!(owned[3], ((0, 1).into(), 'b'));
+            assert_eq!(owned[5], ((2, 1).into(), '#'));
+        });
+    }
+
+    #[test]
+    fn test_collect_and_extend() {
+        let mut grid: Grid<char> = [((1, 1).into(), 'a'), ((3, 2).into(), 'b')]
+            .into_iter()
+            .collect();
+        assert_eq!(grid.bounds(), Rect::new((1, 1).into(), (3, 2).into()));
+        assert_eq!(grid[Coordinate::new(2, 1)], char::default());
+
+        grid.extend([(Coordinate::new(0, 0), 'c')]);
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(grid[Coordinate::new(0, 0)], 'c');
+        assert_eq!(grid[Coordinate::new(3, 2)], 'b');
+
+        let empty: Grid<char> = std::iter::empty().collect();
+        assert_eq!((empty.width(), empty.height()), (1, 1));
+    }
+}
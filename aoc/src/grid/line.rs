@@ -0,0 +1,295 @@
+use std::fmt::Display;
+
+use num::PrimInt;
+
+use super::{coordinate::wide, Coordinate};
+
+/// Straight segment from `start` to `end`, both included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line<N = i32> {
+    pub start: Coordinate<N>,
+    pub end: Coordinate<N>,
+}
+
+/// Where two segments meet, see [`Line::intersection`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Intersection<N = i32> {
+    /// A single point, not necessarily on whole coordinates
+    Point { x: f64, y: f64 },
+    /// Collinear segments sharing more than one point
+    Overlap(Line<N>),
+}
+
+impl<N: PrimInt> Intersection<N> {
+    /// The crossing point if it lies on whole coordinates
+    pub fn as_coordinate(&self) -> Option<Coordinate<N>> {
+        match self {
+            Intersection::Point { x, y } if x.fract() == 0.0 && y.fract() == 0.0 => {
+                Some(Coordinate::new(N::from(*x)?, N::from(*y)?))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<N: PrimInt> Line<N> {
+    pub fn new(start: Coordinate<N>, end: Coordinate<N>) -> Self {
+        Self { start, end }
+    }
+
+    pub fn horizontal(y: N, x_start: N, x_end: N) -> Self {
+        Self {
+            start: Coordinate::new(x_start, y),
+            end: Coordinate::new(x_end, y),
+        }
+    }
+
+    pub fn vertical(x: N, y_start: N, y_end: N) -> Self {
+        Self {
+            start: Coordinate::new(x, y_start),
+            end: Coordinate::new(x, y_end),
+        }
+    }
+
+    /// Cells covered by the segment, rasterized with Bresenham's algorithm
+    pub fn coords(self) -> LineIter<N> {
+        let (start, end) = (widen(self.start), widen(self.end));
+        let (dx, dy) = ((end.0 - start.0).abs(), -(end.1 - start.1).abs());
+
+        LineIter {
+            current: Some(self.start),
+            end: self.end,
+            dx,
+            dy,
+            sx: (end.0 - start.0).signum(),
+            sy: (end.1 - start.1).signum(),
+            err: dx + dy,
+        }
+    }
+
+    /// Walks towards `end` moving one unit on both axes while it can, so slopes other than
+    /// 0, 1 and infinity come out as a diagonal run followed by a straight one
+    pub fn steps(self) -> impl Iterator<Item = Coordinate<N>> {
+        let end = self.end;
+        std::iter::successors(Some(self.start), move |c| {
+            (*c != end).then(|| c.closest(end))
+        })
+    }
+
+    /// Euclidean length
+    pub fn length(&self) -> f64 {
+        let (start, end) = (widen(self.start), widen(self.end));
+        ((end.0 - start.0) as f64).hypot((end.1 - start.1) as f64)
+    }
+
+    /// Whether `point` lies exactly on the segment
+    pub fn contains(&self, point: Coordinate<N>) -> bool {
+        let (a, b, p) = (widen(self.start), widen(self.end), widen(point));
+        cross(sub(b, a), sub(p, a)) == 0
+            && a.0.min(b.0) <= p.0
+            && p.0 <= a.0.max(b.0)
+            && a.1.min(b.1) <= p.1
+            && p.1 <= a.1.max(b.1)
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Where both segments meet, `None` if they don't
+    pub fn intersection(&self, other: &Self) -> Option<Intersection<N>> {
+        let (p, q) = (widen(self.start), widen(other.start));
+        let (r, s) = (sub(widen(self.end), p), sub(widen(other.end), q));
+        let qp = sub(q, p);
+        let denom = cross(r, s);
+
+        if denom == 0 {
+            if cross(qp, r) != 0 || cross(qp, s) != 0 {
+                // Parallel, or a single point next to the other segment
+                return None;
+            }
+            // Collinear, the shared stretch runs between endpoints lying on both segments
+            let shared: Vec<Coordinate<N>> = [self.start, self.end, other.start, other.end]
+                .into_iter()
+                .filter(|c| self.contains(*c) && other.contains(*c))
+                .collect();
+            let min = *shared.iter().min()?;
+            let max = *shared.iter().max()?;
+            return Some(if min == max {
+                Intersection::Point {
+                    x: widen(min).0 as f64,
+                    y: widen(min).1 as f64,
+                }
+            } else {
+                Intersection::Overlap(Line::new(min, max))
+            });
+        }
+
+        // Solve p + t * r = q + u * s, keeping t and u as fractions over denom
+        let (mut t, mut u, mut denom) = (cross(qp, s), cross(qp, r), denom);
+        if denom < 0 {
+            (t, u, denom) = (-t, -u, -denom);
+        }
+        if !(0..=denom).contains(&t) || !(0..=denom).contains(&u) {
+            return None;
+        }
+
+        let t = t as f64 / denom as f64;
+        Some(Intersection::Point {
+            x: p.0 as f64 + t * r.0 as f64,
+            y: p.1 as f64 + t * r.1 as f64,
+        })
+    }
+}
+
+fn widen<N: PrimInt>(c: Coordinate<N>) -> (i128, i128) {
+    (wide(c.x), wide(c.y))
+}
+
+fn sub(a: (i128, i128), b: (i128, i128)) -> (i128, i128) {
+    (a.0 - b.0, a.1 - b.1)
+}
+
+fn cross(a: (i128, i128), b: (i128, i128)) -> i128 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+impl<N: Display> Display for Line<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {}", self.start, self.end)
+    }
+}
+
+/// Bresenham walk over the cells of a [`Line`]
+#[derive(Debug)]
+pub struct LineIter<N = i32> {
+    current: Option<Coordinate<N>>,
+    end: Coordinate<N>,
+    dx: i128,
+    dy: i128,
+    sx: i128,
+    sy: i128,
+    err: i128,
+}
+
+impl<N: PrimInt> Iterator for LineIter<N> {
+    type Item = Coordinate<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current?;
+        if current == self.end {
+            self.current = None;
+            return Some(current);
+        }
+
+        let mut next = current;
+        let e2 = 2 * self.err;
+        if e2 >= self.dy {
+            self.err += self.dy;
+            next.x = step(next.x, self.sx);
+        }
+        if e2 <= self.dx {
+            self.err += self.dx;
+            next.y = step(next.y, self.sy);
+        }
+        self.current = Some(next);
+
+        Some(current)
+    }
+}
+
+/// Moves `n` one unit towards the sign of `sign`, the line never leaves its bounding box
+/// so this can't overflow
+fn step<N: PrimInt>(n: N, sign: i128) -> N {
+    match sign {
+        1 => n + N::one(),
+        -1 => n - N::one(),
+        _ => n,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_iter() {
+        let line = Line::new((1, 1).into(), (3, 4).into());
+        let expected: Vec<Coordinate> =
+            vec![(1, 1).into(), (2, 2).into(), (3, 3).into(), (3, 4).into()];
+
+        assert_eq!(line.steps().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_bresenham() {
+        let line = Line::new((1, 1).into(), (3, 4).into());
+        let expected: Vec<Coordinate> =
+            vec![(1, 1).into(), (2, 2).into(), (2, 3).into(), (3, 4).into()];
+        assert_eq!(line.coords().collect::<Vec<_>>(), expected);
+
+        let shallow = Line::new((6, 2).into(), (0, 0).into());
+        let expected: Vec<Coordinate> = vec![
+            (6, 2).into(),
+            (5, 2).into(),
+            (4, 1).into(),
+            (3, 1).into(),
+            (2, 1).into(),
+            (1, 0).into(),
+            (0, 0).into(),
+        ];
+        assert_eq!(shallow.coords().collect::<Vec<_>>(), expected);
+
+        let point = Line::new((5, 5).into(), (5, 5).into());
+        assert_eq!(point.coords().count(), 1);
+
+        let unsigned = Line::<u8>::new((0, 3).into(), (3, 0).into());
+        assert_eq!(unsigned.coords().count(), 4);
+        assert_eq!(unsigned.coords().last(), Some((3, 0).into()));
+    }
+
+    #[test]
+    fn test_length_and_contains() {
+        let line = Line::new((0, 0).into(), (6, 8).into());
+        assert_eq!(line.length(), 10.0);
+        assert!(line.contains((3, 4).into()));
+        assert!(line.contains((6, 8).into()));
+        assert!(!line.contains((9, 12).into()));
+        assert!(!line.contains((1, 1).into()));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = Line::new((0, 0).into(), (4, 4).into());
+        let b = Line::new((0, 4).into(), (4, 0).into());
+        let cross = a.intersection(&b).unwrap();
+        assert_eq!(cross, Intersection::Point { x: 2.0, y: 2.0 });
+        assert_eq!(cross.as_coordinate(), Some((2, 2).into()));
+
+        let c = Line::new((0, 1).into(), (1, 0).into());
+        assert_eq!(
+            a.intersection(&c),
+            Some(Intersection::Point { x: 0.5, y: 0.5 })
+        );
+        assert_eq!(a.intersection(&c).unwrap().as_coordinate(), None);
+
+        let far = Line::new((5, 0).into(), (9, 0).into());
+        assert!(!b.intersects(&far));
+        assert!(!a.intersects(&Line::new((1, 0).into(), (5, 4).into())));
+
+        let overlap = Line::new((2, 2).into(), (7, 7).into());
+        assert_eq!(
+            a.intersection(&overlap),
+            Some(Intersection::Overlap(Line::new(
+                (2, 2).into(),
+                (4, 4).into()
+            )))
+        );
+        let touching = Line::new((4, 4).into(), (7, 7).into());
+        assert_eq!(
+            a.intersection(&touching),
+            Some(Intersection::Point { x: 4.0, y: 4.0 })
+        );
+        assert!(!a.intersects(&Line::new((5, 5).into(), (7, 7).into())));
+    }
+}
@@ -0,0 +1,661 @@
+use std::fmt::{Debug, Display};
+
+use num::{PrimInt, Signed};
+
+mod ball;
+mod coordinate;
+mod direction;
+mod edge;
+mod image;
+mod iter;
+mod line;
+mod parse;
+mod ray;
+mod record;
+mod rect;
+mod region;
+mod render;
+mod storage;
+mod three;
+mod transform;
+mod view;
+
+pub use ball::ManhattanBall;
+use coordinate::extent;
+pub use coordinate::{bounds, Coordinate};
+pub use direction::{Direction, Direction8, ParseDirectionError, DIRECTIONS, DIRECTIONS8};
+pub use edge::{EdgeFn, EdgePolicy};
+pub use image::{Image, Rgb};
+pub use iter::Iter;
+pub use line::{Intersection, Line, LineIter};
+pub use parse::{Markers, ParseGridError};
+pub use record::{Frame, Recorder};
+pub use rect::Rect;
+pub use region::{ComponentId, Components, LimitExceeded, Neighborhood, Region};
+pub use render::Render;
+pub use storage::StorageKind;
+use storage::{cell_count, dense_coord, dense_index, Storage};
+pub use three::{bounds3, Coordinate3, Grid3, OutOfBounds3};
+pub use transform::{OrientOverflow, Orientation, ORIENTATIONS};
+pub use view::SubGrid;
+
+#[derive(Debug)]
+pub struct Square<N = i32> {
+    min: Coordinate<N>,
+    max: Coordinate<N>,
+}
+
+impl<N: PrimInt> Square<N> {
+    pub fn new(min: Coordinate<N>, max: Coordinate<N>) -> Self {
+        Self { min, max }
+    }
+
+    pub fn coords(self) -> impl Iterator<Item = Coordinate<N>> {
+        let col = Line::new(self.min, self.max.with_x(self.min.x));
+        col.coords().flat_map(move |y| {
+            let row = Line::new(y, y.with_x(self.max.x));
+            row.coords()
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum OverflowType<N = i32> {
+    None,
+    Larger(N),
+    Smaller(N),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct OutOfBounds<N = i32> {
+    coord: Coordinate<N>,
+    x_overflow: OverflowType<N>,
+    y_overflow: OverflowType<N>,
+}
+
+impl<N: Debug + Display> std::error::Error for OutOfBounds<N> {}
+
+impl<N> OutOfBounds<N> {
+    pub fn new(
+        coord: Coordinate<N>,
+        x_overflow: OverflowType<N>,
+        y_overflow: OverflowType<N>,
+    ) -> Self {
+        Self {
+            coord,
+            x_overflow,
+            y_overflow,
+        }
+    }
+}
+
+impl<N: Display> Display for OutOfBounds<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} out of bounds: ", self.coord)?;
+        match &self.x_overflow {
+            OverflowType::None => {}
+            OverflowType::Larger(b) => write!(f, "x={} greater then {}", self.coord.x, b)?,
+            OverflowType::Smaller(b) => write!(f, "x={} smaller then {}", self.coord.x, b)?,
+        }
+        match &self.y_overflow {
+            OverflowType::None => {}
+            OverflowType::Larger(b) => write!(f, "y={} greater then {}", self.coord.y, b)?,
+            OverflowType::Smaller(b) => write!(f, "y={} smaller then {}", self.coord.y, b)?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Grid of `T` values addressed by `Coordinate<N>`
+#[derive(Debug, Clone)]
+pub struct Grid<T: Debug, N = i32> {
+    points: Storage<T, N>,
+    start: Coordinate<N>,
+    end: Coordinate<N>,
+    width: usize,
+    height: usize,
+    empty: T,
+    edge: EdgePolicy<T, N>,
+}
+
+impl<T: Debug, N: PrimInt + Debug + Display> Grid<T, N> {
+    /// Sparse grid spanning `start` to `end`, unset cells read as `empty`
+    pub fn from_coords(start: Coordinate<N>, end: Coordinate<N>, empty: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::with_storage(start, end, empty, StorageKind::Sparse)
+    }
+
+    /// Grid spanning `start` to `end` backed by the given storage
+    pub fn with_storage(
+        start: Coordinate<N>,
+        end: Coordinate<N>,
+        empty: T,
+        kind: StorageKind,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let (start, end) = (
+            Coordinate::new(start.x.min(end.x), start.y.min(end.y)),
+            Coordinate::new(start.x.max(end.x), start.y.max(end.y)),
+        );
+        let (width, height) = Self::dimensions(start, end);
+        let points = Storage::new(kind, width, height, &empty);
+
+        Self {
+            points,
+            width,
+            start,
+            end,
+            height,
+            empty,
+            edge: EdgePolicy::Error,
+        }
+    }
+
+    /// Number of cells between `start` and `end` on each axis
+    ///
+    /// # Panics
+    ///
+    /// When an axis has more cells than `usize` can count.
+    fn dimensions(start: Coordinate<N>, end: Coordinate<N>) -> (usize, usize) {
+        (extent(start.x, end.x), extent(start.y, end.y))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Rectangle covering every coordinate inside the grid
+    pub fn bounds(&self) -> Rect<N> {
+        Rect::new(self.start, self.end)
+    }
+
+    pub fn storage(&self) -> StorageKind {
+        self.points.kind()
+    }
+
+    /// Value of every cell nothing was stored in
+    pub fn empty(&self) -> &T {
+        &self.empty
+    }
+
+    pub fn get(&self, coord: Coordinate<N>) -> &T {
+        match &self.points {
+            Storage::Sparse(points) => points.get(&coord).unwrap_or(&self.empty),
+            Storage::Dense(points) => match self.check_bounds(coord) {
+                Ok(()) => &points[dense_index(self.start, self.width, coord)],
+                Err(_) => &self.empty,
+            },
+        }
+    }
+
+    /// Stores `val` at `coord` without touching the bounds.
+    ///
+    /// # Panics
+    ///
+    /// A dense grid has nowhere to put cells outside its bounds, so it panics on those.
+    /// Use [`Grid::set_resize`] to grow it instead.
+    pub fn set(&mut self, coord: Coordinate<N>, val: T) {
+        if let Storage::Dense(_) = self.points {
+            if let Err(e) = self.check_bounds(coord) {
+                panic!("{e}");
+            }
+        }
+        match &mut self.points {
+            Storage::Sparse(points) => {
+                points.insert(coord, val);
+            }
+            Storage::Dense(points) => {
+                points[dense_index(self.start, self.width, coord)] = val;
+            }
+        }
+    }
+
+    pub fn get_bounded(&self, coord: Coordinate<N>) -> Result<&T, OutOfBounds<N>> {
+        self.check_bounds(coord)?;
+        Ok(self.get(coord))
+    }
+
+    pub fn set_bounded(&mut self, coord: Coordinate<N>, val: T) -> Result<(), OutOfBounds<N>> {
+        self.check_bounds(coord)?;
+        self.set(coord, val);
+
+        Ok(())
+    }
+
+    pub fn get_resize(&mut self, coord: Coordinate<N>) -> &T
+    where
+        T: Clone,
+    {
+        self.check_and_resize(coord);
+        self.get(coord)
+    }
+
+    pub fn set_resize(&mut self, coord: Coordinate<N>, val: T)
+    where
+        T: Clone,
+    {
+        self.check_and_resize(coord);
+        self.set(coord, val)
+    }
+
+    fn check_bounds(&self, coord: Coordinate<N>) -> Result<(), OutOfBounds<N>> {
+        let mut x_overflow = OverflowType::None;
+        let mut y_overflow = OverflowType::None;
+
+        if coord.x < self.start.x {
+            x_overflow = OverflowType::Smaller(self.start.x);
+        } else if coord.x > self.end.x {
+            x_overflow = OverflowType::Larger(self.end.x);
+        }
+        if coord.y < self.start.y {
+            y_overflow = OverflowType::Smaller(self.start.y);
+        } else if coord.y > self.end.y {
+            y_overflow = OverflowType::Larger(self.end.y);
+        }
+
+        if let (&OverflowType::None, &OverflowType::None) = (&x_overflow, &y_overflow) {
+            Ok(())
+        } else {
+            Err(OutOfBounds::new(coord, x_overflow, y_overflow))
+        }
+    }
+
+    fn check_and_resize(&mut self, coord: Coordinate<N>)
+    where
+        T: Clone,
+    {
+        if let Err(e) = self.check_bounds(coord) {
+            let (mut start, mut end) = (self.start, self.end);
+            match e.x_overflow {
+                OverflowType::None => {}
+                OverflowType::Larger(_) => end.x = coord.x,
+                OverflowType::Smaller(_) => start.x = coord.x,
+            }
+            match e.y_overflow {
+                OverflowType::None => {}
+                OverflowType::Larger(_) => end.y = coord.y,
+                OverflowType::Smaller(_) => start.y = coord.y,
+            }
+            self.resize(start, end);
+        }
+    }
+
+    /// Moves the bounds to `start`..`end`, keeping the width, height and a dense buffer in sync.
+    /// Cells of a dense grid that fall outside the new bounds are dropped.
+    fn resize(&mut self, start: Coordinate<N>, end: Coordinate<N>)
+    where
+        T: Clone,
+    {
+        let (width, height) = Self::dimensions(start, end);
+        if let Storage::Dense(points) = &mut self.points {
+            let old =
+                std::mem::replace(points, vec![self.empty.clone(); cell_count(width, height)]);
+            for (i, val) in old.into_iter().enumerate() {
+                let coord = dense_coord(self.start, self.width, i);
+                let inside = start.x <= coord.x
+                    && coord.x <= end.x
+                    && start.y <= coord.y
+                    && coord.y <= end.y;
+                if inside {
+                    points[dense_index(start, width, coord)] = val;
+                }
+            }
+        }
+
+        self.start = start;
+        self.end = end;
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Smallest rectangle holding every cell inside the bounds that differs from `empty`,
+    /// `None` when there are none
+    pub fn occupied_bounds(&self) -> Option<Rect<N>>
+    where
+        T: PartialEq,
+    {
+        let inside = self.bounds();
+        let occupied: Vec<Coordinate<N>> = match &self.points {
+            Storage::Sparse(points) => points
+                .iter()
+                .filter(|(c, v)| inside.contains(**c) && **v != self.empty)
+                .map(|(c, _)| *c)
+                .collect(),
+            Storage::Dense(points) => points
+                .iter()
+                .enumerate()
+                .filter(|(_, v)| **v != self.empty)
+                .map(|(i, _)| dense_coord(self.start, self.width, i))
+                .collect(),
+        };
+        let (min, max) = bounds(&occupied)?;
+
+        Some(Rect::new(min, max))
+    }
+
+    /// Shrinks the bounds to [`Grid::occupied_bounds`], a grid with nothing in it is left alone
+    pub fn shrink_to_fit(&mut self)
+    where
+        T: Clone + PartialEq,
+    {
+        if let Some(rect) = self.occupied_bounds() {
+            self.resize(rect.min, rect.max);
+        }
+    }
+
+    /// Grows the bounds by `n` cells on every side, stopping at the limits of `N`
+    pub fn pad(&mut self, n: N)
+    where
+        T: Clone,
+    {
+        self.pad_x(n);
+        self.pad_y(n);
+    }
+
+    /// Grows the bounds by `n` cells on the left and on the right
+    pub fn pad_x(&mut self, n: N)
+    where
+        T: Clone,
+    {
+        let start = self.start.with_x(self.start.x.saturating_sub(n));
+        let end = self.end.with_x(self.end.x.saturating_add(n));
+        self.resize(start, end);
+    }
+
+    /// Grows the bounds by `n` cells at the top and at the bottom
+    pub fn pad_y(&mut self, n: N)
+    where
+        T: Clone,
+    {
+        let start = self.start.with_y(self.start.y.saturating_sub(n));
+        let end = self.end.with_y(self.end.y.saturating_add(n));
+        self.resize(start, end);
+    }
+
+    pub fn coords(&self) -> Vec<Coordinate<N>> {
+        num::range_inclusive(self.start.y, self.end.y)
+            .flat_map(|y| {
+                num::range_inclusive(self.start.x, self.end.x).map(move |x| Coordinate::new(x, y))
+            })
+            .collect()
+    }
+
+    pub fn coords_at_x(&self, x: N) -> Result<Vec<Coordinate<N>>, OutOfBounds<N>> {
+        let start = self.start.with_x(x);
+        let end = self.end.with_x(x);
+
+        self.check_bounds(start)?;
+        self.check_bounds(end)?;
+
+        Ok(Line::new(start, end).coords().collect())
+    }
+
+    pub fn coords_at_y(&self, y: N) -> Result<Vec<Coordinate<N>>, OutOfBounds<N>> {
+        let start = self.start.with_y(y);
+        let end = self.end.with_y(y);
+
+        self.check_bounds(start)?;
+        self.check_bounds(end)?;
+
+        Ok(Line::new(start, end).coords().collect())
+    }
+
+    /// Coordinates within `distance` steps of `coord` that pass `filter`
+    pub fn coords_in_area<F>(
+        &self,
+        coord: Coordinate<N>,
+        distance: N,
+        filter: F,
+    ) -> impl Iterator<Item = Coordinate<N>>
+    where
+        N: Signed,
+        F: Fn(Coordinate<N>) -> bool,
+    {
+        ManhattanBall::new(coord, distance)
+            .coords()
+            .filter(move |c| filter(*c))
+    }
+
+    pub fn display(&self)
+    where
+        T: Display,
+    {
+        print!("{}", self);
+    }
+}
+
+impl<T: Debug + Display, N: PrimInt + Debug + Display> Display for Grid<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, coord) in self.coords().into_iter().enumerate() {
+            write!(f, "{}", self.get(coord))?;
+            if ((i + 1) % self.width) == 0 {
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Runs `test` on the same grid with every kind of storage, `empty` from `start` to `end`
+/// with `cells` set
+#[cfg(test)]
+pub(crate) fn for_each_storage<T: Debug + Clone, N: PrimInt + Debug + Display>(
+    (start, end): ((N, N), (N, N)),
+    empty: T,
+    cells: &[((N, N), T)],
+    mut test: impl FnMut(Grid<T, N>),
+) {
+    for kind in [StorageKind::Sparse, StorageKind::Dense] {
+        let mut grid = Grid::with_storage(start.into(), end.into(), empty.clone(), kind);
+        for (coord, value) in cells {
+            grid.set((*coord).into(), value.clone());
+        }
+        test(grid);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPAN: ((i32, i32), (i32, i32)) = ((0, 0), (2, 1));
+
+    #[test]
+    fn test_grid_get_set() {
+        let mut kinds = Vec::new();
+        for_each_storage(SPAN, '.', &[], |mut grid| {
+            kinds.push(grid.storage());
+            assert_eq!(*grid.get((1, 1).into()), '.');
+
+            grid.set((1, 1).into(), '#');
+            assert_eq!(*grid.get((1, 1).into()), '#');
+            assert_eq!(*grid.get((5, 5).into()), '.');
+        });
+        assert_eq!(kinds, [StorageKind::Sparse, StorageKind::Dense]);
+    }
+
+    #[test]
+    fn test_grid_bounded() {
+        for_each_storage(SPAN, '.', &[], |mut grid| {
+            assert!(grid.get_bounded((2, 1).into()).is_ok());
+            assert!(grid.get_bounded((3, 1).into()).is_err());
+            assert!(grid.get_bounded((0, -1).into()).is_err());
+            assert!(grid.set_bounded((3, 0).into(), '#').is_err());
+            assert_eq!(*grid.get((3, 0).into()), '.');
+        });
+    }
+
+    #[test]
+    fn test_grid_set_resize() {
+        for_each_storage(SPAN, '.', &[], |mut grid| {
+            grid.set((0, 0).into(), 'a');
+            grid.set_resize((-1, 3).into(), 'b');
+
+            assert_eq!((grid.width(), grid.height()), (4, 4));
+            assert_eq!(*grid.get_bounded((0, 0).into()).unwrap(), 'a');
+            assert_eq!(*grid.get_bounded((-1, 3).into()).unwrap(), 'b');
+            assert_eq!(*grid.get_bounded((2, 2).into()).unwrap(), '.');
+        });
+    }
+
+    #[test]
+    fn test_grid_coords() {
+        for_each_storage(SPAN, '.', &[], |grid| {
+            let expected: Vec<Coordinate> = vec![
+                (0, 0).into(),
+                (1, 0).into(),
+                (2, 0).into(),
+                (0, 1).into(),
+                (1, 1).into(),
+                (2, 1).into(),
+            ];
+
+            assert_eq!(grid.coords(), expected);
+        });
+    }
+
+    #[test]
+    fn test_grid_generic() {
+        let mut grid: Grid<char, usize> =
+            Grid::with_storage((0, 0).into(), (2, 1).into(), '.', StorageKind::Dense);
+        grid.set_resize((4, 2).into(), '#');
+        assert_eq!((grid.width(), grid.height()), (5, 3));
+        assert_eq!(*grid.get((4, 2).into()), '#');
+
+        let big: Grid<char, i64> =
+            Grid::from_coords((0, 0).into(), (4_000_000, 4_000_000).into(), '.');
+        assert_eq!(big.width(), 4_000_001);
+        assert_eq!(
+            Line::<u8>::horizontal(3, 1, 3).coords().collect::<Vec<_>>(),
+            vec![(1, 3).into(), (2, 3).into(), (3, 3).into()]
+        );
+    }
+
+    #[test]
+    fn test_full_range() {
+        let (min, max) = (i8::MIN, i8::MAX);
+        for_each_storage(
+            ((min, min), (max, max)),
+            '.',
+            &[((max, min), '#')],
+            |mut grid| {
+                assert_eq!((grid.width(), grid.height()), (256, 256));
+                assert_eq!(grid.bounds().width(), 256);
+                assert_eq!(grid.image(|_| Rgb::BLACK).width(), 256);
+                assert_eq!(*grid.get((max, min).into()), '#');
+                assert_eq!(*grid.get_bounded((min, 5).into()).unwrap(), '.');
+
+                assert_eq!(grid.iter().len(), 256 * 256);
+                assert_eq!(grid.iter().nth(255), Some(((max, min).into(), &'#')));
+                assert_eq!(grid.iter().last(), Some(((max, max).into(), &'.')));
+
+                grid.shrink_to_fit();
+                assert_eq!(
+                    grid.bounds(),
+                    Rect::new((max, min).into(), (max, min).into())
+                );
+                grid.set_resize((min, max).into(), 'x');
+                assert_eq!((grid.width(), grid.height()), (256, 256));
+                assert_eq!(*grid.get((max, min).into()), '#');
+                assert_eq!(*grid.get((min, max).into()), 'x');
+            },
+        );
+    }
+
+    #[test]
+    fn test_occupied_bounds() {
+        for_each_storage(SPAN, '.', &[], |mut grid| {
+            assert_eq!(grid.occupied_bounds(), None);
+            grid.shrink_to_fit();
+            assert_eq!((grid.width(), grid.height()), (3, 2));
+
+            grid.pad(2);
+            assert_eq!((grid.width(), grid.height()), (7, 6));
+            assert_eq!(grid.bounds(), Rect::new((-2, -2).into(), (4, 3).into()));
+            grid.set((-1, 3).into(), '#');
+            grid.set((3, -2).into(), '#');
+            grid.set((0, 0).into(), '.');
+            assert_eq!(
+                grid.occupied_bounds(),
+                Some(Rect::new((-1, -2).into(), (3, 3).into()))
+            );
+
+            grid.shrink_to_fit();
+            assert_eq!((grid.width(), grid.height()), (5, 6));
+            assert_eq!(grid.bounds(), grid.occupied_bounds().unwrap());
+            assert_eq!(*grid.get_bounded((3, -2).into()).unwrap(), '#');
+
+            grid.pad_x(1);
+            grid.pad_y(0);
+            assert_eq!((grid.width(), grid.height()), (7, 6));
+        });
+    }
+
+    #[test]
+    fn test_huge_sparse() {
+        let mut grid: Grid<char, i64> = Grid::from_coords(
+            (-5_000_000_000, -5_000_000_000).into(),
+            (5_000_000_000, 5_000_000_000).into(),
+            '.',
+        );
+        assert_eq!(grid.width(), 10_000_000_001);
+        grid.set((4, -2).into(), '#');
+        assert_eq!(*grid.get((4, -2).into()), '#');
+    }
+
+    #[test]
+    #[should_panic(expected = "grid has more cells than usize can count")]
+    fn test_huge_dense() {
+        let far = (5_000_000_000, 5_000_000_000);
+        Grid::<char, i64>::with_storage(
+            (-far.0, -far.1).into(),
+            far.into(),
+            '.',
+            StorageKind::Dense,
+        );
+    }
+
+    #[test]
+    fn test_shrink_then_shrink_to_fit() {
+        let cells = [((0, 0), '#'), ((2, 2), '#')];
+        for_each_storage(((0, 0), (4, 4)), '.', &cells, |mut grid| {
+            // A negative pad shrinks, leaving (0, 0) outside the bounds
+            grid.pad(-1);
+            assert_eq!(grid.bounds(), Rect::new((1, 1).into(), (3, 3).into()));
+            assert_eq!(
+                grid.occupied_bounds(),
+                Some(Rect::new((2, 2).into(), (2, 2).into()))
+            );
+
+            grid.shrink_to_fit();
+            assert_eq!(grid.bounds(), Rect::new((2, 2).into(), (2, 2).into()));
+        });
+    }
+
+    #[test]
+    fn test_pad_unsigned() {
+        let mut grid: Grid<char, u8> =
+            Grid::with_storage((1, 0).into(), (3, 0).into(), '.', StorageKind::Dense);
+        grid.set((2, 0).into(), '#');
+        grid.pad(3);
+        assert_eq!(grid.bounds(), Rect::new((0, 0).into(), (6, 3).into()));
+        assert_eq!(*grid.get((2, 0).into()), '#');
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dense_set_out_of_bounds() {
+        let mut grid = Grid::with_storage((0, 0).into(), (2, 1).into(), '.', StorageKind::Dense);
+        grid.set((3, 0).into(), '#');
+    }
+}
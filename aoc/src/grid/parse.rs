@@ -0,0 +1,156 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display},
+};
+
+use num::PrimInt;
+
+use super::{Coordinate, Grid, StorageKind};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseGridError {
+    /// Input had no lines at all
+    Empty,
+    /// Line `line` (0 based) has a different length than the first line
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// Input has more rows or columns than the coordinate type can address
+    TooLarge,
+}
+
+impl std::error::Error for ParseGridError {}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "grid input is empty"),
+            ParseGridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {} has {} characters, expected {}",
+                line, found, expected
+            ),
+            ParseGridError::TooLarge => write!(f, "grid input is too large for its coordinates"),
+        }
+    }
+}
+
+/// Coordinates of the marker characters found while parsing a grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Markers<N = i32>(BTreeMap<char, Vec<Coordinate<N>>>);
+
+impl<N> Default for Markers<N> {
+    fn default() -> Self {
+        Self(BTreeMap::new())
+    }
+}
+
+impl<N: Copy> Markers<N> {
+    /// First occurrence of `marker`, in reading order
+    pub fn get(&self, marker: char) -> Option<Coordinate<N>> {
+        self.all(marker).first().copied()
+    }
+
+    /// Every occurrence of `marker`, in reading order
+    pub fn all(&self, marker: char) -> &[Coordinate<N>] {
+        self.0.get(&marker).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+impl<T: Debug + Clone, N: PrimInt + Debug + Display> Grid<T, N> {
+    /// Builds a dense grid from lines of text, mapping every character with `f`.
+    ///
+    /// The top left character is at `(0, 0)`, x grows to the right and y grows downwards.
+    /// Positions of any character in `markers` are collected as well, they're still passed to `f`.
+    pub fn parse<F>(
+        input: &str,
+        empty: T,
+        markers: &[char],
+        mut f: F,
+    ) -> Result<(Self, Markers<N>), ParseGridError>
+    where
+        F: FnMut(char) -> T,
+    {
+        let lines: Vec<&str> = input.lines().collect();
+        let width = lines.first().ok_or(ParseGridError::Empty)?.chars().count();
+        if width == 0 {
+            return Err(ParseGridError::Empty);
+        }
+
+        let end = Coordinate::new(width - 1, lines.len() - 1)
+            .try_cast()
+            .ok_or(ParseGridError::TooLarge)?;
+        let origin = Coordinate::new(N::zero(), N::zero());
+        let mut grid = Grid::with_storage(origin, end, empty, StorageKind::Dense);
+        let mut found = Markers::default();
+
+        for (y, line) in lines.iter().enumerate() {
+            let len = line.chars().count();
+            if len != width {
+                return Err(ParseGridError::Ragged {
+                    line: y,
+                    expected: width,
+                    found: len,
+                });
+            }
+
+            for (x, c) in line.chars().enumerate() {
+                let coord = Coordinate::new(N::from(x).unwrap(), N::from(y).unwrap());
+                if markers.contains(&c) {
+                    found.0.entry(c).or_default().push(coord);
+                }
+                grid.set(coord, f(c));
+            }
+        }
+
+        Ok((grid, found))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let (grid, markers): (Grid<u32>, _) =
+            Grid::parse("Sab\ncdE\nxyS", 0, &['S', 'E'], |c| c as u32).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(*grid.get((1, 0).into()), 'a' as u32);
+        assert_eq!(*grid.get((0, 2).into()), 'x' as u32);
+        assert_eq!(markers.get('S'), Some((0, 0).into()));
+        assert_eq!(markers.all('S'), &[(0, 0).into(), (2, 2).into()]);
+        assert_eq!(markers.get('E'), Some((2, 1).into()));
+        assert_eq!(markers.get('Q'), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Grid::<char>::parse("", '.', &[], |c| c).unwrap_err(),
+            ParseGridError::Empty
+        );
+        assert_eq!(
+            Grid::<char>::parse("...\n..\n...", '.', &[], |c| c).unwrap_err(),
+            ParseGridError::Ragged {
+                line: 1,
+                expected: 3,
+                found: 2
+            }
+        );
+
+        let wide = ".".repeat(300);
+        assert_eq!(
+            Grid::<char, u8>::parse(&wide, '.', &[], |c| c).unwrap_err(),
+            ParseGridError::TooLarge
+        );
+        assert!(Grid::<char, usize>::parse(&wide, '.', &[], |c| c).is_ok());
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt::{Debug, Display};
+
+use num::PrimInt;
+
+use super::{Coordinate, Direction8, Grid};
+
+impl<T: Debug, N: PrimInt + Debug + Display> Grid<T, N> {
+    /// Cells seen looking from `from` towards `direction`, nearest first, `from` itself left
+    /// out. Stops at the bounds whatever the grid's edge policy is.
+    pub fn ray(
+        &self,
+        from: Coordinate<N>,
+        direction: impl Into<Direction8>,
+    ) -> impl Iterator<Item = (Coordinate<N>, &T)> + '_ {
+        let direction = direction.into();
+        std::iter::successors(Some(from), move |c| {
+            c.checked_offset_direction(direction, N::one())
+        })
+        .skip(1)
+        .map_while(|c| Some((c, self.get_bounded(c).ok()?)))
+    }
+
+    /// First cell along [`Grid::ray`] that matches `predicate`, `None` when the edge comes first
+    pub fn walk_until<F>(
+        &self,
+        from: Coordinate<N>,
+        direction: impl Into<Direction8>,
+        predicate: F,
+    ) -> Option<(Coordinate<N>, &T)>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.ray(from, direction).find(|(_, val)| predicate(val))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Direction;
+
+    fn grid() -> Grid<u32> {
+        Grid::parse("30373\n25512\n65332", 0, &[], |c| c.to_digit(10).unwrap())
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = grid();
+        let right: Vec<_> = grid.ray((1, 1).into(), Direction::Right).collect();
+        assert_eq!(
+            right,
+            vec![
+                ((2, 1).into(), &5),
+                ((3, 1).into(), &1),
+                ((4, 1).into(), &2)
+            ]
+        );
+        let down: Vec<u32> = grid
+            .ray((1, 0).into(), Direction::Down)
+            .map(|(_, v)| *v)
+            .collect();
+        assert_eq!(down, vec![5, 5]);
+        assert_eq!(grid.ray((1, 0).into(), Direction::Up).count(), 0);
+        assert_eq!(grid.ray((0, 0).into(), Direction8::DownLeft).count(), 0);
+        assert_eq!(grid.ray((0, 0).into(), Direction8::DownRight).count(), 2);
+    }
+
+    #[test]
+    fn test_ray_unsigned() {
+        let grid: Grid<char, usize> = Grid::parse("abc", '.', &[], |c| c).unwrap().0;
+        let left: String = grid
+            .ray((2, 0).into(), Direction::Left)
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(left, "ba");
+    }
+
+    #[test]
+    fn test_walk_until() {
+        let grid = grid();
+        assert_eq!(
+            grid.walk_until((0, 1).into(), Direction::Right, |h| *h >= 2),
+            Some(((1, 1).into(), &5))
+        );
+        assert_eq!(
+            grid.walk_until((0, 1).into(), Direction::Right, |h| *h >= 6),
+            None
+        );
+    }
+}
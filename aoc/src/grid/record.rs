@@ -0,0 +1,406 @@
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display, Write},
+    io,
+    path::Path,
+};
+
+use num::PrimInt;
+
+use super::{Grid, Image, Rect, Rgb};
+
+/// One recorded state of a grid, every cell already mapped by the recorder
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame<P, N = i32> {
+    pub bounds: Rect<N>,
+    /// Cells row by row, like [`Rect::coords`]
+    pub cells: Vec<P>,
+}
+
+impl<N: PrimInt> Display for Frame<char, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.chunks(self.bounds.width()) {
+            for c in row {
+                f.write_char(*c)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Change from one frame to the next
+#[derive(Debug)]
+enum Delta<P, N> {
+    /// The bounds changed, so the whole frame is kept
+    Full(Frame<P, N>),
+    /// Cell indexes that changed and their new value
+    Changes(Vec<(usize, P)>),
+}
+
+impl<P: Clone, N: Copy> Delta<P, N> {
+    fn apply(&self, frame: &mut Frame<P, N>) {
+        match self {
+            Delta::Full(full) => *frame = full.clone(),
+            Delta::Changes(changes) => {
+                for (i, p) in changes {
+                    frame.cells[*i] = p.clone();
+                }
+            }
+        }
+    }
+
+    fn bytes(&self) -> usize {
+        match self {
+            Delta::Full(full) => frame_bytes(full),
+            Delta::Changes(changes) => std::mem::size_of_val(changes.as_slice()),
+        }
+    }
+}
+
+fn frame_bytes<P, N>(frame: &Frame<P, N>) -> usize {
+    std::mem::size_of_val(frame.cells.as_slice())
+}
+
+/// Records the states of a [`Grid`] while a simulation runs.
+///
+/// Every cell is mapped to a `P` when recorded, a `char` for an asciicast or an [`Rgb`] for
+/// an image sequence. Only the oldest and newest kept frames are stored in full, the others
+/// as changes from the frame before. When the frames outgrow the memory budget the oldest
+/// ones are dropped, the newest frame is always kept.
+#[derive(Debug)]
+pub struct Recorder<P, N = i32> {
+    every: usize,
+    budget: usize,
+    steps: usize,
+    dropped: usize,
+    bytes: usize,
+    /// Oldest kept frame, `None` when it's also the newest
+    first: Option<Frame<P, N>>,
+    deltas: VecDeque<Delta<P, N>>,
+    last: Option<Frame<P, N>>,
+}
+
+impl<P, N> Default for Recorder<P, N> {
+    fn default() -> Self {
+        Self {
+            every: 1,
+            budget: usize::MAX,
+            steps: 0,
+            dropped: 0,
+            bytes: 0,
+            first: None,
+            deltas: VecDeque::new(),
+            last: None,
+        }
+    }
+}
+
+impl<P: Clone + PartialEq, N: PrimInt + Debug + Display> Recorder<P, N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only keep every `n`th step passed to [`Recorder::record`], starting with the first
+    ///
+    /// # Panics
+    ///
+    /// When `n` is zero.
+    pub fn every(mut self, n: usize) -> Self {
+        assert!(n > 0, "frame interval must be at least 1");
+        self.every = n;
+        self
+    }
+
+    /// Drop the oldest frames once the recorded cells take more than `bytes`
+    pub fn budget(mut self, bytes: usize) -> Self {
+        self.budget = bytes;
+        self
+    }
+
+    /// Counts a step and captures `grid` if it isn't skipped, returns whether it was captured
+    pub fn record<T, F>(&mut self, grid: &Grid<T, N>, cell: F) -> bool
+    where
+        T: Debug,
+        F: Fn(&T) -> P,
+    {
+        self.steps += 1;
+        if !(self.steps - 1).is_multiple_of(self.every) {
+            return false;
+        }
+        self.capture(grid, cell);
+
+        true
+    }
+
+    /// Captures `grid` whatever the frame interval, for the final state of a simulation
+    pub fn capture<T, F>(&mut self, grid: &Grid<T, N>, cell: F)
+    where
+        T: Debug,
+        F: Fn(&T) -> P,
+    {
+        let bounds = grid.bounds();
+        let frame = Frame {
+            bounds,
+            cells: bounds.coords().map(|c| cell(grid.get(c))).collect(),
+        };
+
+        if let Some(last) = self.last.take() {
+            let delta = if last.bounds == frame.bounds {
+                Delta::Changes(
+                    frame
+                        .cells
+                        .iter()
+                        .zip(&last.cells)
+                        .enumerate()
+                        .filter(|(_, (new, old))| new != old)
+                        .map(|(i, (new, _))| (i, new.clone()))
+                        .collect(),
+                )
+            } else {
+                Delta::Full(frame.clone())
+            };
+            self.bytes += delta.bytes();
+            self.deltas.push_back(delta);
+            if self.first.is_none() {
+                self.first = Some(last);
+            } else {
+                self.bytes -= frame_bytes(&last);
+            }
+        }
+        self.bytes += frame_bytes(&frame);
+        self.last = Some(frame);
+
+        self.shrink();
+    }
+
+    /// Folds the oldest changes into the first frame until the budget fits
+    fn shrink(&mut self) {
+        while self.bytes > self.budget {
+            let Some(delta) = self.deltas.pop_front() else {
+                break;
+            };
+            self.bytes -= delta.bytes();
+            if self.deltas.is_empty() {
+                // Caught up with the newest frame, which is already stored in full
+                if let Some(first) = self.first.take() {
+                    self.bytes -= frame_bytes(&first);
+                }
+            } else if let Some(first) = &mut self.first {
+                self.bytes -= frame_bytes(first);
+                delta.apply(first);
+                self.bytes += frame_bytes(first);
+            }
+            self.dropped += 1;
+        }
+    }
+
+    /// Number of steps passed to [`Recorder::record`], skipped ones included
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Number of frames currently kept
+    pub fn len(&self) -> usize {
+        self.last.iter().count() + self.deltas.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.last.is_none()
+    }
+
+    /// Number of frames dropped to stay within the budget
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Bytes taken by the kept frames
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Kept frames, oldest first
+    pub fn frames(&self) -> impl Iterator<Item = Frame<P, N>> + '_ {
+        let mut current = self.first.as_ref().or(self.last.as_ref()).cloned();
+        let mut deltas = self.deltas.iter();
+        std::iter::from_fn(move || {
+            let frame = current.clone()?;
+            current = deltas.next().map(|delta| {
+                let mut next = frame.clone();
+                delta.apply(&mut next);
+                next
+            });
+            Some(frame)
+        })
+    }
+}
+
+impl<N: PrimInt + Debug + Display> Recorder<char, N> {
+    /// Asciicast v2 recording playing `fps` frames per second
+    pub fn to_asciicast(&self, fps: f64) -> String {
+        let width = self.frames().map(|f| f.bounds.width()).max().unwrap_or(0);
+        let height = self.frames().map(|f| f.bounds.height()).max().unwrap_or(0);
+
+        let mut out = format!(
+            "{{\"version\": 2, \"width\": {}, \"height\": {}}}\n",
+            width, height
+        );
+        for (i, frame) in self.frames().enumerate() {
+            // Clear the screen before every frame, terminals want \r\n line endings
+            let text = format!("\x1b[H\x1b[2J{}", frame).replace('\n', "\r\n");
+            out += &format!(
+                "[{:.6}, \"o\", \"{}\"]\n",
+                i as f64 / fps,
+                json_escape(&text)
+            );
+        }
+
+        out
+    }
+
+    pub fn save_asciicast(&self, path: impl AsRef<Path>, fps: f64) -> io::Result<()> {
+        std::fs::write(path, self.to_asciicast(fps))
+    }
+}
+
+impl<N: PrimInt + Debug + Display> Recorder<Rgb, N> {
+    /// Frames as images, scaled by `scale`
+    pub fn images(&self, scale: usize) -> impl Iterator<Item = Image<N>> + '_ {
+        self.frames()
+            .map(move |f| Image::from_pixels(f.bounds, f.cells).scaled(scale))
+    }
+
+    /// Writes every frame to `dir` as `frame_00000.png`, `frame_00001.png` and so on,
+    /// returns the number of files written
+    pub fn save_images(&self, dir: impl AsRef<Path>, scale: usize) -> io::Result<usize> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        let mut count = 0;
+        for (i, image) in self.images(scale).enumerate() {
+            image.save(dir.join(format!("frame_{:05}.png", i)))?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+}
+
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c if c.is_control() => out += &format!("\\u{:04x}", c as u32),
+            c => out.push(c),
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cell(b: &bool) -> char {
+        if *b {
+            '#'
+        } else {
+            '.'
+        }
+    }
+
+    fn walk(recorder: &mut Recorder<char>) {
+        let mut grid = Grid::from_coords((0, 0).into(), (2, 0).into(), false);
+        for x in 0..3 {
+            grid.set((x, 0).into(), true);
+            recorder.record(&grid, cell);
+        }
+        grid.set_resize((0, 1).into(), true);
+        recorder.record(&grid, cell);
+    }
+
+    #[test]
+    fn test_frames() {
+        let mut recorder = Recorder::new();
+        walk(&mut recorder);
+
+        let frames: Vec<String> = recorder.frames().map(|f| f.to_string()).collect();
+        assert_eq!(frames, vec!["#..\n", "##.\n", "###\n", "###\n#..\n"]);
+        assert_eq!(recorder.steps(), 4);
+        assert_eq!(recorder.dropped(), 0);
+    }
+
+    #[test]
+    fn test_every() {
+        let mut recorder = Recorder::new().every(2);
+        walk(&mut recorder);
+
+        let frames: Vec<String> = recorder.frames().map(|f| f.to_string()).collect();
+        assert_eq!(frames, vec!["#..\n", "###\n"]);
+        assert_eq!(recorder.steps(), 4);
+    }
+
+    #[test]
+    fn test_budget() {
+        let char_size = std::mem::size_of::<char>();
+        let mut recorder = Recorder::new().budget(8 * char_size);
+        walk(&mut recorder);
+
+        let frames: Vec<String> = recorder.frames().map(|f| f.to_string()).collect();
+        assert_eq!(frames, vec!["###\n#..\n"]);
+        assert_eq!(recorder.len(), 1);
+        assert_eq!(recorder.dropped(), 3);
+        assert_eq!(recorder.bytes(), 6 * char_size);
+    }
+
+    #[test]
+    fn test_budget_counts_newest() {
+        let char_size = std::mem::size_of::<char>();
+        let mut recorder = Recorder::new().budget(3 * char_size);
+        let grid = Grid::from_coords((0, 0).into(), (2, 0).into(), false);
+        for _ in 0..3 {
+            recorder.record(&grid, cell);
+        }
+
+        assert_eq!(recorder.len(), 1);
+        assert_eq!(recorder.dropped(), 2);
+        assert_eq!(recorder.bytes(), 3 * char_size);
+        assert_eq!(recorder.frames().count(), 1);
+    }
+
+    #[test]
+    fn test_asciicast() {
+        let mut recorder = Recorder::new();
+        walk(&mut recorder);
+
+        let cast = recorder.to_asciicast(2.0);
+        let lines: Vec<&str> = cast.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0], r#"{"version": 2, "width": 3, "height": 2}"#);
+        assert_eq!(lines[2], r#"[0.500000, "o", "\u001b[H\u001b[2J##.\r\n"]"#);
+    }
+
+    #[test]
+    fn test_images() {
+        let mut recorder = Recorder::new();
+        let mut grid = Grid::from_coords((0, 0).into(), (1, 1).into(), 0u8);
+        for level in [10, 20] {
+            grid.set((1, 1).into(), level);
+            recorder.record(&grid, |l| Rgb::gray(*l));
+        }
+
+        let pgms: Vec<Vec<u8>> = recorder.images(1).map(|i| i.to_pgm()).collect();
+        assert_eq!(&pgms[1][pgms[1].len() - 4..], &[0, 0, 0, 20]);
+
+        let dir =
+            std::env::temp_dir().join(format!("aoc_{}_recorder_test_images", std::process::id()));
+        assert_eq!(recorder.save_images(&dir, 2).unwrap(), 2);
+        assert!(dir.join("frame_00001.png").exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
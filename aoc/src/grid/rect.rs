@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+use num::PrimInt;
+
+use super::{coordinate::extent, Coordinate};
+
+/// Axis aligned rectangle spanning `min` to `max`, both corners included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect<N = i32> {
+    pub min: Coordinate<N>,
+    pub max: Coordinate<N>,
+}
+
+impl<N: PrimInt> Rect<N> {
+    /// Rectangle with `a` and `b` as opposite corners, in any order
+    pub fn new(a: Coordinate<N>, b: Coordinate<N>) -> Self {
+        Self {
+            min: Coordinate::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Coordinate::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn contains(&self, coord: Coordinate<N>) -> bool {
+        self.min.x <= coord.x
+            && coord.x <= self.max.x
+            && self.min.y <= coord.y
+            && coord.y <= self.max.y
+    }
+
+    pub fn width(&self) -> usize {
+        extent(self.min.x, self.max.x)
+    }
+
+    pub fn height(&self) -> usize {
+        extent(self.min.y, self.max.y)
+    }
+
+    /// Overlapping part of both rectangles, `None` if they don't touch
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = Coordinate::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Coordinate::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        if min.x > max.x || min.y > max.y {
+            None
+        } else {
+            Some(Self { min, max })
+        }
+    }
+
+    /// Every coordinate inside, row by row
+    pub fn coords(self) -> impl Iterator<Item = Coordinate<N>> {
+        num::range_inclusive(self.min.y, self.max.y).flat_map(move |y| {
+            num::range_inclusive(self.min.x, self.max.x).map(move |x| Coordinate::new(x, y))
+        })
+    }
+}
+
+impl<N: Display> Display for Rect<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..={}", self.min, self.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect() {
+        let rect = Rect::new((3, -1).into(), (0, 2).into());
+        assert_eq!(rect.min, Coordinate::new(0, -1));
+        assert_eq!((rect.width(), rect.height()), (4, 4));
+        assert!(rect.contains((3, 2).into()));
+        assert!(!rect.contains((4, 0).into()));
+        assert_eq!(rect.coords().count(), 16);
+        assert_eq!(rect.to_string(), "(0, -1)..=(3, 2)");
+
+        let other = Rect::new((2, 2).into(), (5, 5).into());
+        assert_eq!(
+            rect.intersection(&other),
+            Some(Rect::new((2, 2).into(), (3, 2).into()))
+        );
+        assert_eq!(
+            rect.intersection(&Rect::new((9, 9).into(), (9, 9).into())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_full_range() {
+        let rect = Rect::<i8>::new((i8::MIN, i8::MIN).into(), (i8::MAX, 0).into());
+        assert_eq!((rect.width(), rect.height()), (256, 129));
+    }
+}
@@ -0,0 +1,306 @@
+use std::{
+    collections::{BTreeSet, VecDeque},
+    fmt::{Debug, Display},
+};
+
+use num::PrimInt;
+
+use super::{Coordinate, Grid, Rect, StorageKind};
+
+/// Which cells count as adjacent
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Neighborhood {
+    /// Orthogonal neighbors only
+    #[default]
+    Four,
+    /// Diagonals included
+    Eight,
+}
+
+impl Neighborhood {
+    pub fn neighbors<N: PrimInt>(self, coord: Coordinate<N>) -> Vec<Coordinate<N>> {
+        match self {
+            Neighborhood::Four => coord.neighbors4().collect(),
+            Neighborhood::Eight => coord.neighbors8().collect(),
+        }
+    }
+}
+
+/// Label of a connected component, regions are numbered from 1 in reading order
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ComponentId(pub usize);
+
+impl ComponentId {
+    /// Label of the cells that aren't part of any region
+    pub const BACKGROUND: ComponentId = ComponentId(0);
+
+    pub fn is_background(self) -> bool {
+        self == Self::BACKGROUND
+    }
+}
+
+impl Display for ComponentId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Set of connected cells
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region<N = i32> {
+    pub cells: BTreeSet<Coordinate<N>>,
+}
+
+impl<N: PrimInt> Region<N> {
+    pub fn contains(&self, coord: Coordinate<N>) -> bool {
+        self.cells.contains(&coord)
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Number of cell sides that don't touch another cell of the region
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|c| 4 - c.neighbors4().filter(|n| self.contains(*n)).count())
+            .sum()
+    }
+
+    /// Smallest rectangle holding every cell, `None` for an empty region
+    pub fn bounds(&self) -> Option<Rect<N>> {
+        let first = self.cells.first()?;
+        let (min, max) = self.cells.iter().fold((*first, *first), |(min, max), c| {
+            (
+                Coordinate::new(min.x.min(c.x), min.y.min(c.y)),
+                Coordinate::new(max.x.max(c.x), max.y.max(c.y)),
+            )
+        });
+
+        Some(Rect::new(min, max))
+    }
+}
+
+/// A flood fill reached more cells than its limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LimitExceeded {
+    pub limit: usize,
+}
+
+impl std::error::Error for LimitExceeded {}
+
+impl Display for LimitExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "flood fill reached more than {} cells", self.limit)
+    }
+}
+
+/// Connected components of a grid, see [`Grid::components`]
+#[derive(Debug)]
+pub struct Components<N = i32> {
+    /// Component of every cell, [`ComponentId::BACKGROUND`] for impassable ones
+    pub labels: Grid<ComponentId, N>,
+    /// Regions in id order, region `i` has id `i + 1`
+    pub regions: Vec<Region<N>>,
+}
+
+impl<N: PrimInt> Components<N> {
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn region(&self, id: ComponentId) -> Option<&Region<N>> {
+        self.regions.get(id.0.checked_sub(1)?)
+    }
+}
+
+impl<T: Debug, N: PrimInt + Debug + Display> Grid<T, N> {
+    /// Every passable cell reachable from `seed` without leaving the grid, empty when the
+    /// seed itself isn't passable or is out of bounds
+    pub fn flood_fill<F>(
+        &self,
+        seed: Coordinate<N>,
+        neighborhood: Neighborhood,
+        passable: F,
+    ) -> Region<N>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let inside = |c: Coordinate<N>| self.get_bounded(c).is_ok_and(&passable);
+        fill(seed, neighborhood, inside, usize::MAX).unwrap()
+    }
+
+    /// Flood fill that ignores the grid bounds, cells outside read as empty.
+    ///
+    /// Meant for sparse grids that don't know their own extent, the fill gives up once it
+    /// has found more than `limit` cells.
+    pub fn flood_fill_unbounded<F>(
+        &self,
+        seed: Coordinate<N>,
+        neighborhood: Neighborhood,
+        limit: usize,
+        passable: F,
+    ) -> Result<Region<N>, LimitExceeded>
+    where
+        F: Fn(&T) -> bool,
+    {
+        fill(seed, neighborhood, |c| passable(self.get(c)), limit)
+    }
+
+    /// Labels every group of connected passable cells inside the grid
+    pub fn components<F>(&self, neighborhood: Neighborhood, passable: F) -> Components<N>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut labels = Grid::with_storage(
+            self.start,
+            self.end,
+            ComponentId::BACKGROUND,
+            StorageKind::Dense,
+        );
+        let mut regions = Vec::new();
+
+        for coord in self.coords() {
+            if !labels.get(coord).is_background() || !passable(self.get(coord)) {
+                continue;
+            }
+            let region = self.flood_fill(coord, neighborhood, &passable);
+            let id = ComponentId(regions.len() + 1);
+            for cell in &region.cells {
+                labels.set(*cell, id);
+            }
+            regions.push(region);
+        }
+
+        Components { labels, regions }
+    }
+}
+
+fn fill<N, F>(
+    seed: Coordinate<N>,
+    neighborhood: Neighborhood,
+    passable: F,
+    limit: usize,
+) -> Result<Region<N>, LimitExceeded>
+where
+    N: PrimInt,
+    F: Fn(Coordinate<N>) -> bool,
+{
+    let mut cells = BTreeSet::new();
+    if !passable(seed) {
+        return Ok(Region { cells });
+    }
+
+    let mut queue = VecDeque::from([seed]);
+    cells.insert(seed);
+    while let Some(coord) = queue.pop_front() {
+        for next in neighborhood.neighbors(coord) {
+            if !cells.contains(&next) && passable(next) {
+                if cells.len() == limit {
+                    return Err(LimitExceeded { limit });
+                }
+                cells.insert(next);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Ok(Region { cells })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "\
+##..#
+#...#
+..#..
+.#.##";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(MAP, '.', &[], |c| c).unwrap().0
+    }
+
+    fn wall(c: &char) -> bool {
+        *c == '#'
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = grid();
+        let region = grid.flood_fill((0, 0).into(), Neighborhood::Four, wall);
+        assert_eq!(region.area(), 3);
+        assert_eq!(region.perimeter(), 8);
+        assert_eq!(
+            region.bounds(),
+            Some(Rect::new((0, 0).into(), (1, 1).into()))
+        );
+
+        let open = grid.flood_fill((2, 0).into(), Neighborhood::Four, |c| *c == '.');
+        assert_eq!(open.area(), 10);
+        assert!(grid
+            .flood_fill((0, 0).into(), Neighborhood::Four, |c| *c == '.')
+            .is_empty());
+        assert!(grid
+            .flood_fill((9, 9).into(), Neighborhood::Four, wall)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_components() {
+        let grid = grid();
+
+        let four = grid.components(Neighborhood::Four, wall);
+        assert_eq!(four.len(), 5);
+        let areas: Vec<usize> = four.regions.iter().map(Region::area).collect();
+        assert_eq!(areas, vec![3, 2, 1, 1, 2]);
+        assert_eq!(*four.labels.get((4, 1).into()), ComponentId(2));
+        assert_eq!(*four.labels.get((2, 1).into()), ComponentId::BACKGROUND);
+        assert_eq!(four.region(ComponentId(3)).unwrap().area(), 1);
+        assert_eq!(four.region(ComponentId::BACKGROUND), None);
+
+        let eight = grid.components(Neighborhood::Eight, wall);
+        assert_eq!(eight.len(), 3);
+        assert_eq!(
+            eight
+                .labels
+                .render(|id| char::from_digit(id.0 as u32, 10).unwrap())
+                .to_string(),
+            "11002\n10002\n00300\n03033\n"
+        );
+    }
+
+    #[test]
+    fn test_unbounded() {
+        // A closed ring of walls reaching past the grid bounds
+        let mut grid: Grid<char> = Grid::from_coords((0, 0).into(), (1, 1).into(), '.');
+        for coord in Rect::new((-1, -1).into(), (3, 3).into()).coords() {
+            if coord.x == -1 || coord.x == 3 || coord.y == -1 || coord.y == 3 {
+                grid.set(coord, '#');
+            }
+        }
+
+        let inside =
+            grid.flood_fill_unbounded((0, 0).into(), Neighborhood::Four, 100, |c| *c == '.');
+        assert_eq!(inside.map(|r| r.area()), Ok(9));
+        assert_eq!(
+            grid.flood_fill_unbounded((5, 5).into(), Neighborhood::Four, 100, |c| *c == '.'),
+            Err(LimitExceeded { limit: 100 })
+        );
+        assert_eq!(
+            grid.flood_fill((0, 0).into(), Neighborhood::Four, |c| *c == '.')
+                .area(),
+            4
+        );
+    }
+}
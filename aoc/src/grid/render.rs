@@ -0,0 +1,169 @@
+use std::fmt::{Debug, Display};
+
+use num::PrimInt;
+
+use super::{Grid, Rect};
+
+/// Text rendering of a [`Grid`], one char per cell, built with [`Grid::render`].
+///
+/// Every row ends with a newline, use `to_string` to get it as a `String`.
+pub struct Render<'a, T: Debug, N, F> {
+    grid: &'a Grid<T, N>,
+    cell: F,
+    viewport: Option<Rect<N>>,
+    flip_y: bool,
+    rulers: bool,
+}
+
+impl<T: Debug, N: PrimInt + Debug + Display> Grid<T, N> {
+    /// Renders the grid by mapping every cell to a char with `cell`
+    pub fn render<F>(&self, cell: F) -> Render<'_, T, N, F>
+    where
+        F: Fn(&T) -> char,
+    {
+        Render {
+            grid: self,
+            cell,
+            viewport: None,
+            flip_y: false,
+            rulers: false,
+        }
+    }
+}
+
+impl<T: Debug, N: PrimInt + Debug + Display, F: Fn(&T) -> char> Render<'_, T, N, F> {
+    /// Only show the cells inside `viewport`, cells outside the grid show up as empty
+    pub fn viewport(mut self, viewport: Rect<N>) -> Self {
+        self.viewport = Some(viewport);
+        self
+    }
+
+    /// Put the largest y at the top, for puzzles where up means +y
+    pub fn flip_y(mut self) -> Self {
+        self.flip_y = true;
+        self
+    }
+
+    /// Label rows on the left and columns on top with their coordinates
+    pub fn rulers(mut self) -> Self {
+        self.rulers = true;
+        self
+    }
+}
+
+impl<T: Debug, N: PrimInt + Debug + Display, F: Fn(&T) -> char> Display for Render<'_, T, N, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let view = self.viewport.unwrap_or_else(|| self.grid.bounds());
+        let mut ys: Vec<N> = num::range_inclusive(view.min.y, view.max.y).collect();
+        if self.flip_y {
+            ys.reverse();
+        }
+
+        let row_width = if self.rulers {
+            ys.iter().map(|y| y.to_string().len()).max().unwrap_or(0)
+        } else {
+            0
+        };
+
+        if self.rulers {
+            // Column labels are written top to bottom, right aligned like numbers
+            let labels: Vec<String> = num::range_inclusive(view.min.x, view.max.x)
+                .map(|x| x.to_string())
+                .collect();
+            let height = labels.iter().map(String::len).max().unwrap_or(0);
+            for i in 0..height {
+                write!(f, "{:row_width$} ", "")?;
+                for label in &labels {
+                    let pad = height - label.len();
+                    let c = if i < pad {
+                        ' '
+                    } else {
+                        label.as_bytes()[i - pad] as char
+                    };
+                    write!(f, "{}", c)?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        for y in ys {
+            if self.rulers {
+                write!(f, "{:>row_width$} ", y)?;
+            }
+            for x in num::range_inclusive(view.min.x, view.max.x) {
+                write!(f, "{}", (self.cell)(self.grid.get((x, y).into())))?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Coordinate;
+
+    fn grid() -> Grid<bool> {
+        let mut grid = Grid::from_coords((-1, 0).into(), (10, 2).into(), false);
+        for coord in [(-1, 0), (0, 1), (10, 2), (9, 0)] {
+            grid.set(coord.into(), true);
+        }
+        grid
+    }
+
+    fn cell(b: &bool) -> char {
+        if *b {
+            '#'
+        } else {
+            '.'
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = grid();
+        assert_eq!(
+            grid.render(cell).to_string(),
+            "#.........#.\n.#..........\n...........#\n"
+        );
+        assert_eq!(
+            grid.render(cell).flip_y().to_string(),
+            "...........#\n.#..........\n#.........#.\n"
+        );
+    }
+
+    #[test]
+    fn test_render_viewport() {
+        let grid = grid();
+        let view = Rect::new(Coordinate::new(-2, 1), Coordinate::new(1, 3));
+        assert_eq!(
+            grid.render(cell).viewport(view).to_string(),
+            "..#.\n....\n....\n"
+        );
+    }
+
+    #[test]
+    fn test_render_rulers() {
+        let grid = grid();
+        let view = Rect::new(Coordinate::new(-1, 0), Coordinate::new(10, 1));
+        let expected = concat!(
+            "  -          1\n",
+            "  101234567890\n",
+            "0 #.........#.\n",
+            "1 .#..........\n",
+        );
+        assert_eq!(
+            grid.render(cell).viewport(view).rulers().to_string(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let mut grid = Grid::from_coords((0, 0).into(), (2, 1).into(), 0);
+        grid.set((1, 1).into(), 7);
+        assert_eq!(grid.to_string(), "000\n070\n");
+    }
+}
@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+
+use num::PrimInt;
+
+use super::{
+    coordinate::{abs_diff, wide},
+    Coordinate,
+};
+
+/// Backend used by a [`Grid`](super::Grid) to hold its cells, picked when the grid is built
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StorageKind {
+    /// Only cells that were set are kept, in a `BTreeMap`. Cheap for huge, mostly empty grids.
+    #[default]
+    Sparse,
+    /// Every cell inside the bounds is kept in a row-major `Vec`. Fast for simulations.
+    Dense,
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum Storage<T, N> {
+    Sparse(BTreeMap<Coordinate<N>, T>),
+    Dense(Vec<T>),
+}
+
+impl<T, N> Storage<T, N> {
+    /// Storage for a `width` by `height` grid, only a dense one needs its cell count to fit
+    pub(super) fn new(kind: StorageKind, width: usize, height: usize, empty: &T) -> Self
+    where
+        T: Clone,
+    {
+        match kind {
+            StorageKind::Sparse => Self::Sparse(BTreeMap::new()),
+            StorageKind::Dense => Self::Dense(vec![empty.clone(); cell_count(width, height)]),
+        }
+    }
+
+    pub(super) fn kind(&self) -> StorageKind {
+        match self {
+            Storage::Sparse(_) => StorageKind::Sparse,
+            Storage::Dense(_) => StorageKind::Dense,
+        }
+    }
+}
+
+/// Number of cells in a `width` by `height` grid
+///
+/// # Panics
+///
+/// When there are more than `usize` can count.
+pub(super) fn cell_count(width: usize, height: usize) -> usize {
+    width
+        .checked_mul(height)
+        .expect("grid has more cells than usize can count")
+}
+
+/// Position of `coord` in a row-major buffer starting at `start` with rows of `width` cells.
+/// The caller has to make sure `coord` is inside the buffer.
+pub(super) fn dense_index<N: PrimInt>(
+    start: Coordinate<N>,
+    width: usize,
+    coord: Coordinate<N>,
+) -> usize {
+    let offset = |from: N, to: N| usize::try_from(abs_diff(from, to)).unwrap();
+
+    offset(start.y, coord.y) * width + offset(start.x, coord.x)
+}
+
+/// Inverse of [`dense_index`]
+pub(super) fn dense_coord<N: PrimInt>(
+    start: Coordinate<N>,
+    width: usize,
+    index: usize,
+) -> Coordinate<N> {
+    let axis = |from: N, offset: usize| N::from(wide(from) + offset as i128).unwrap();
+
+    Coordinate::new(axis(start.x, index % width), axis(start.y, index / width))
+}
@@ -0,0 +1,428 @@
+use std::{
+    collections::{BTreeMap, HashSet, VecDeque},
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::{Add, Sub},
+};
+
+use num::PrimInt;
+
+use super::coordinate::{abs_diff, extent};
+
+/// Abstract coordinate in a three dimensional space
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct Coordinate3<N = i32> {
+    pub x: N,
+    pub y: N,
+    pub z: N,
+}
+
+impl<N> Coordinate3<N> {
+    pub const fn new(x: N, y: N, z: N) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<N: PrimInt> Coordinate3<N> {
+    pub fn offset(self, xd: N, yd: N, zd: N) -> Self {
+        Self {
+            x: self.x + xd,
+            y: self.y + yd,
+            z: self.z + zd,
+        }
+    }
+
+    /// Moves by a signed unit step per axis, `None` if the result doesn't fit in `N`
+    fn checked_step(self, (xd, yd, zd): (i8, i8, i8)) -> Option<Self> {
+        fn step<N: PrimInt>(v: N, d: i8) -> Option<N> {
+            match d {
+                1 => v.checked_add(&N::one()),
+                -1 => v.checked_sub(&N::one()),
+                _ => Some(v),
+            }
+        }
+
+        Some(Self {
+            x: step(self.x, xd)?,
+            y: step(self.y, yd)?,
+            z: step(self.z, zd)?,
+        })
+    }
+
+    /// The six face-adjacent coordinates, skipping any that don't fit in `N`
+    pub fn neighbors6(self) -> impl Iterator<Item = Self> {
+        [
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 1, 0),
+            (0, -1, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ]
+        .into_iter()
+        .filter_map(move |step| self.checked_step(step))
+    }
+
+    /// All 26 surrounding coordinates, skipping any that don't fit in `N`
+    pub fn neighbors26(self) -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| (x, y, z))))
+            .filter(|step| *step != (0, 0, 0))
+            .filter_map(move |step| self.checked_step(step))
+    }
+
+    /// Distance walking along the axes, like [`Coordinate::manhattan_distance`]
+    pub fn manhattan_distance(self, other: Self) -> u128 {
+        abs_diff(self.x, other.x)
+            .saturating_add(abs_diff(self.y, other.y))
+            .saturating_add(abs_diff(self.z, other.z))
+    }
+
+    /// Converts to another integer width, `None` if any axis doesn't fit
+    pub fn try_cast<M: PrimInt>(self) -> Option<Coordinate3<M>> {
+        Some(Coordinate3 {
+            x: M::from(self.x)?,
+            y: M::from(self.y)?,
+            z: M::from(self.z)?,
+        })
+    }
+
+    fn within(self, min: Self, max: Self) -> bool {
+        (min.x..=max.x).contains(&self.x)
+            && (min.y..=max.y).contains(&self.y)
+            && (min.z..=max.z).contains(&self.z)
+    }
+}
+
+impl<N: Display> Display for Coordinate3<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl<N> From<(N, N, N)> for Coordinate3<N> {
+    fn from((x, y, z): (N, N, N)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<N: PrimInt> Sub for Coordinate3<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl<N: PrimInt> Add for Coordinate3<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+pub fn bounds3<N: PrimInt>(coords: &[Coordinate3<N>]) -> Option<(Coordinate3<N>, Coordinate3<N>)> {
+    let min_x = coords.iter().map(|c| c.x).min()?;
+    let min_y = coords.iter().map(|c| c.y).min()?;
+    let min_z = coords.iter().map(|c| c.z).min()?;
+    let max_x = coords.iter().map(|c| c.x).max()?;
+    let max_y = coords.iter().map(|c| c.y).max()?;
+    let max_z = coords.iter().map(|c| c.z).max()?;
+
+    Some(((min_x, min_y, min_z).into(), (max_x, max_y, max_z).into()))
+}
+
+#[derive(Debug)]
+pub struct OutOfBounds3<N = i32> {
+    coord: Coordinate3<N>,
+    start: Coordinate3<N>,
+    end: Coordinate3<N>,
+}
+
+impl<N: Debug + Display> std::error::Error for OutOfBounds3<N> {}
+
+impl<N: Display> Display for OutOfBounds3<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} out of bounds: outside {} - {}",
+            self.coord, self.start, self.end
+        )
+    }
+}
+
+/// Sparse voxel grid of `T` values addressed by `Coordinate3<N>`
+#[derive(Debug)]
+pub struct Grid3<T: Debug, N = i32> {
+    points: BTreeMap<Coordinate3<N>, T>,
+    start: Coordinate3<N>,
+    end: Coordinate3<N>,
+    empty: T,
+}
+
+impl<T: Debug, N: PrimInt + Hash + Debug + Display> Grid3<T, N> {
+    /// Grid spanning `start` to `end`, unset cells read as `empty`
+    pub fn from_coords(start: Coordinate3<N>, end: Coordinate3<N>, empty: T) -> Self {
+        Self {
+            points: BTreeMap::new(),
+            start: Coordinate3::new(start.x.min(end.x), start.y.min(end.y), start.z.min(end.z)),
+            end: Coordinate3::new(start.x.max(end.x), start.y.max(end.y), start.z.max(end.z)),
+            empty,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        extent(self.start.x, self.end.x)
+    }
+
+    pub fn height(&self) -> usize {
+        extent(self.start.y, self.end.y)
+    }
+
+    pub fn depth(&self) -> usize {
+        extent(self.start.z, self.end.z)
+    }
+
+    /// Smallest and largest coordinate inside the grid
+    pub fn bounds(&self) -> (Coordinate3<N>, Coordinate3<N>) {
+        (self.start, self.end)
+    }
+
+    pub fn get(&self, coord: Coordinate3<N>) -> &T {
+        self.points.get(&coord).unwrap_or(&self.empty)
+    }
+
+    /// Stores `val` at `coord` without touching the bounds
+    pub fn set(&mut self, coord: Coordinate3<N>, val: T) {
+        self.points.insert(coord, val);
+    }
+
+    pub fn get_bounded(&self, coord: Coordinate3<N>) -> Result<&T, OutOfBounds3<N>> {
+        self.check_bounds(coord)?;
+        Ok(self.get(coord))
+    }
+
+    pub fn set_bounded(&mut self, coord: Coordinate3<N>, val: T) -> Result<(), OutOfBounds3<N>> {
+        self.check_bounds(coord)?;
+        self.set(coord, val);
+
+        Ok(())
+    }
+
+    pub fn get_resize(&mut self, coord: Coordinate3<N>) -> &T {
+        self.check_and_resize(coord);
+        self.get(coord)
+    }
+
+    pub fn set_resize(&mut self, coord: Coordinate3<N>, val: T) {
+        self.check_and_resize(coord);
+        self.set(coord, val)
+    }
+
+    fn check_bounds(&self, coord: Coordinate3<N>) -> Result<(), OutOfBounds3<N>> {
+        if coord.within(self.start, self.end) {
+            Ok(())
+        } else {
+            Err(OutOfBounds3 {
+                coord,
+                start: self.start,
+                end: self.end,
+            })
+        }
+    }
+
+    fn check_and_resize(&mut self, coord: Coordinate3<N>) {
+        self.start = Coordinate3::new(
+            self.start.x.min(coord.x),
+            self.start.y.min(coord.y),
+            self.start.z.min(coord.z),
+        );
+        self.end = Coordinate3::new(
+            self.end.x.max(coord.x),
+            self.end.y.max(coord.y),
+            self.end.z.max(coord.z),
+        );
+    }
+
+    /// Every coordinate inside the bounds, x changing fastest and z slowest
+    pub fn coords(&self) -> Vec<Coordinate3<N>> {
+        Self::range(self.start, self.end).collect()
+    }
+
+    fn range(start: Coordinate3<N>, end: Coordinate3<N>) -> impl Iterator<Item = Coordinate3<N>> {
+        num::range_inclusive(start.z, end.z).flat_map(move |z| {
+            num::range_inclusive(start.y, end.y).flat_map(move |y| {
+                num::range_inclusive(start.x, end.x).map(move |x| Coordinate3::new(x, y, z))
+            })
+        })
+    }
+
+    /// Non solid cells connected to the outside of the grid.
+    ///
+    /// The flood fill starts from a one cell shell around the bounds so pockets open to any
+    /// side are found, cells enclosed by solid ones are left out.
+    pub fn exterior<F>(&self, solid: F) -> HashSet<Coordinate3<N>>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let (min, max) = self.shell();
+        let on_shell = |c: &Coordinate3<N>| {
+            c.x == min.x
+                || c.x == max.x
+                || c.y == min.y
+                || c.y == max.y
+                || c.z == min.z
+                || c.z == max.z
+        };
+        let mut seen: HashSet<_> = Self::range(min, max)
+            .filter(|c| on_shell(c) && !solid(self.get(*c)))
+            .collect();
+        let mut queue: VecDeque<_> = seen.iter().copied().collect();
+        while let Some(coord) = queue.pop_front() {
+            for next in coord.neighbors6() {
+                if next.within(min, max) && !solid(self.get(next)) && seen.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        seen
+    }
+
+    /// Number of solid cell faces that don't touch another solid cell
+    pub fn surface_area<F>(&self, solid: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.solid_cells(&solid)
+            .map(|coord| {
+                coord.neighbors6().filter(|n| !solid(self.get(*n))).count()
+                    + (6 - coord.neighbors6().count())
+            })
+            .sum()
+    }
+
+    /// Like [`Grid3::surface_area`] but only counting faces reachable from outside
+    pub fn exterior_surface_area<F>(&self, solid: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        let (min, max) = self.shell();
+        let exterior = self.exterior(&solid);
+        self.solid_cells(&solid)
+            .map(|coord| {
+                coord
+                    .neighbors6()
+                    .filter(|n| !n.within(min, max) || exterior.contains(n))
+                    .count()
+                    + (6 - coord.neighbors6().count())
+            })
+            .sum()
+    }
+
+    fn solid_cells<'a, F>(&'a self, solid: &'a F) -> impl Iterator<Item = Coordinate3<N>> + 'a
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.points
+            .iter()
+            .filter(move |(_, v)| solid(v))
+            .map(|(c, _)| *c)
+    }
+
+    /// Bounds grown by one cell on every side, as far as `N` allows
+    fn shell(&self) -> (Coordinate3<N>, Coordinate3<N>) {
+        let grow = |v: N, f: fn(&N, &N) -> Option<N>| f(&v, &N::one()).unwrap_or(v);
+        let min = Coordinate3::new(
+            grow(self.start.x, N::checked_sub),
+            grow(self.start.y, N::checked_sub),
+            grow(self.start.z, N::checked_sub),
+        );
+        let max = Coordinate3::new(
+            grow(self.end.x, N::checked_add),
+            grow(self.end.y, N::checked_add),
+            grow(self.end.z, N::checked_add),
+        );
+
+        (min, max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Example droplet from 2022 day 18
+    const DROPLET: [(i32, i32, i32); 13] = [
+        (2, 2, 2),
+        (1, 2, 2),
+        (3, 2, 2),
+        (2, 1, 2),
+        (2, 3, 2),
+        (2, 2, 1),
+        (2, 2, 3),
+        (2, 2, 4),
+        (2, 2, 6),
+        (1, 2, 5),
+        (3, 2, 5),
+        (2, 1, 5),
+        (2, 3, 5),
+    ];
+
+    fn droplet() -> Grid3<bool> {
+        let cubes: Vec<Coordinate3> = DROPLET.iter().map(|c| (*c).into()).collect();
+        let (min, max) = bounds3(&cubes).unwrap();
+        let mut grid = Grid3::from_coords(min, max, false);
+        for cube in cubes {
+            grid.set_bounded(cube, true).unwrap();
+        }
+
+        grid
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let coord = Coordinate3::new(1, 1, 1);
+        assert_eq!(coord.neighbors6().count(), 6);
+        assert_eq!(coord.neighbors26().count(), 26);
+        assert!(coord.neighbors6().all(|n| n.manhattan_distance(coord) == 1));
+        assert_eq!(Coordinate3::<u32>::new(0, 0, 0).neighbors26().count(), 7);
+    }
+
+    #[test]
+    fn test_grid3_bounds() {
+        let mut grid = Grid3::from_coords((0, 0, 0).into(), (1, 1, 1).into(), '.');
+        assert!(grid.get_bounded((2, 0, 0).into()).is_err());
+        grid.set_resize((2, -1, 0).into(), '#');
+        assert_eq!((grid.width(), grid.height(), grid.depth()), (3, 3, 2));
+        assert_eq!(*grid.get_bounded((2, -1, 0).into()).unwrap(), '#');
+        assert_eq!(grid.coords().len(), 18);
+    }
+
+    #[test]
+    fn test_surface_area() {
+        let grid = droplet();
+        assert_eq!(grid.surface_area(|c| *c), 64);
+        assert_eq!(grid.exterior_surface_area(|c| *c), 58);
+        assert!(!grid.exterior(|c| *c).contains(&(2, 2, 5).into()));
+    }
+
+    #[test]
+    fn test_surface_area_unsigned() {
+        let mut grid: Grid3<bool, u8> =
+            Grid3::from_coords((0, 0, 0).into(), (1, 0, 0).into(), false);
+        grid.set((0, 0, 0).into(), true);
+        grid.set((1, 0, 0).into(), true);
+        assert_eq!(grid.surface_area(|c| *c), 10);
+        assert_eq!(grid.exterior_surface_area(|c| *c), 10);
+    }
+}
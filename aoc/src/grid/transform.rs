@@ -0,0 +1,263 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display},
+};
+
+use num::PrimInt;
+
+use super::{coordinate::wide, storage::dense_index, Coordinate, Grid, Rect, Storage};
+
+/// One of the eight ways to rotate and mirror a grid.
+///
+/// Directions are as the grid renders, with y growing downwards like [`Grid::parse`] reads it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Identity,
+    RotateCw,
+    Rotate180,
+    RotateCcw,
+    /// Mirrored left to right
+    FlipHorizontal,
+    /// Mirrored top to bottom
+    FlipVertical,
+    /// Mirrored along the diagonal through the top left corner
+    Transpose,
+    /// Mirrored along the diagonal through the top right corner
+    AntiTranspose,
+}
+
+/// Every orientation, the identity first
+pub const ORIENTATIONS: [Orientation; 8] = [
+    Orientation::Identity,
+    Orientation::RotateCw,
+    Orientation::Rotate180,
+    Orientation::RotateCcw,
+    Orientation::FlipHorizontal,
+    Orientation::FlipVertical,
+    Orientation::Transpose,
+    Orientation::AntiTranspose,
+];
+
+impl Orientation {
+    /// Whether width and height trade places
+    pub fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Orientation::RotateCw
+                | Orientation::RotateCcw
+                | Orientation::Transpose
+                | Orientation::AntiTranspose
+        )
+    }
+
+    /// Where the cell `(x, y)` of a `width` by `height` area ends up, relative to its top
+    /// left corner
+    pub fn map(self, (x, y): (i128, i128), width: i128, height: i128) -> (i128, i128) {
+        let (w, h) = (width - 1, height - 1);
+        match self {
+            Orientation::Identity => (x, y),
+            Orientation::RotateCw => (h - y, x),
+            Orientation::Rotate180 => (w - x, h - y),
+            Orientation::RotateCcw => (y, w - x),
+            Orientation::FlipHorizontal => (w - x, y),
+            Orientation::FlipVertical => (x, h - y),
+            Orientation::Transpose => (y, x),
+            Orientation::AntiTranspose => (h - y, w - x),
+        }
+    }
+}
+
+/// Turning a grid would push its bounds past the largest coordinate `N` holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrientOverflow {
+    pub orientation: Orientation,
+    /// Size of the grid once turned
+    pub width: usize,
+    pub height: usize,
+}
+
+impl std::error::Error for OrientOverflow {}
+
+impl Display for OrientOverflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} grid of {}x{} cells doesn't fit its coordinates",
+            self.orientation, self.width, self.height
+        )
+    }
+}
+
+impl<T: Debug + Clone, N: PrimInt + Debug + Display> Grid<T, N> {
+    /// The grid turned or mirrored as told by `orientation`.
+    ///
+    /// The top left corner stays at the same coordinate, the bounds grow from there. Cells a
+    /// sparse grid has outside its bounds are mirrored too, unless they'd no longer fit `N`.
+    /// Fails when the bounds themselves no longer fit.
+    pub fn orient(self, orientation: Orientation) -> Result<Self, OrientOverflow> {
+        let (width, height) = (self.width, self.height);
+        let (new_width, new_height) = if orientation.swaps_axes() {
+            (height, width)
+        } else {
+            (width, height)
+        };
+        let start = self.start;
+        let reach = |from: N, len: usize| from.checked_add(&N::from(len - 1)?);
+        let (Some(end_x), Some(end_y)) = (reach(start.x, new_width), reach(start.y, new_height))
+        else {
+            return Err(OrientOverflow {
+                orientation,
+                width: new_width,
+                height: new_height,
+            });
+        };
+        let end = Coordinate::new(end_x, end_y);
+        let map = |coord: Coordinate<N>| -> Option<Coordinate<N>> {
+            let rel = (wide(coord.x) - wide(start.x), wide(coord.y) - wide(start.y));
+            let (x, y) = orientation.map(rel, width as i128, height as i128);
+            Some(Coordinate::new(
+                N::from(wide(start.x) + x)?,
+                N::from(wide(start.y) + y)?,
+            ))
+        };
+
+        let points = match self.points {
+            Storage::Sparse(points) => Storage::Sparse(
+                points
+                    .into_iter()
+                    .filter_map(|(c, v)| Some((map(c)?, v)))
+                    .collect::<BTreeMap<_, _>>(),
+            ),
+            Storage::Dense(points) => {
+                let mut moved = vec![self.empty.clone(); points.len()];
+                let old_coords = Rect::new(start, self.end).coords();
+                for (coord, val) in old_coords.zip(points) {
+                    let coord = map(coord).unwrap();
+                    moved[dense_index(start, new_width, coord)] = val;
+                }
+                Storage::Dense(moved)
+            }
+        };
+
+        Ok(Self {
+            points,
+            start,
+            end,
+            width: new_width,
+            height: new_height,
+            empty: self.empty,
+            edge: self.edge,
+        })
+    }
+
+    /// Quarter turn clockwise
+    pub fn rotate_cw(self) -> Result<Self, OrientOverflow> {
+        self.orient(Orientation::RotateCw)
+    }
+
+    /// Quarter turn counter clockwise
+    pub fn rotate_ccw(self) -> Result<Self, OrientOverflow> {
+        self.orient(Orientation::RotateCcw)
+    }
+
+    /// Mirrored left to right
+    pub fn flip_horizontal(self) -> Result<Self, OrientOverflow> {
+        self.orient(Orientation::FlipHorizontal)
+    }
+
+    /// Mirrored top to bottom
+    pub fn flip_vertical(self) -> Result<Self, OrientOverflow> {
+        self.orient(Orientation::FlipVertical)
+    }
+
+    /// Rows become columns
+    pub fn transpose(self) -> Result<Self, OrientOverflow> {
+        self.orient(Orientation::Transpose)
+    }
+
+    /// The grid in all eight orientations, in the order of [`ORIENTATIONS`]
+    pub fn orientations(
+        &self,
+    ) -> impl Iterator<Item = Result<(Orientation, Self), OrientOverflow>> + '_ {
+        ORIENTATIONS
+            .into_iter()
+            .map(|o| Ok((o, self.clone().orient(o)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::for_each_storage;
+
+    const SPAN: ((i32, i32), (i32, i32)) = ((2, 5), (4, 6));
+    const CELLS: [((i32, i32), char); 4] =
+        [((2, 5), 'a'), ((3, 5), 'b'), ((4, 5), 'c'), ((2, 6), 'd')];
+
+    #[test]
+    fn test_rotate() {
+        for_each_storage(SPAN, '.', &CELLS, |grid| {
+            assert_eq!(grid.to_string(), "abc\nd..\n");
+
+            let cw = grid.clone().rotate_cw().unwrap();
+            assert_eq!((cw.width(), cw.height()), (2, 3));
+            assert_eq!(cw.bounds().min, Coordinate::new(2, 5));
+            assert_eq!(cw.bounds().max, Coordinate::new(3, 7));
+            assert_eq!(cw.to_string(), "da\n.b\n.c\n");
+            assert_eq!(
+                grid.clone().rotate_ccw().unwrap().to_string(),
+                "c.\nb.\nad\n"
+            );
+            let back = cw
+                .rotate_cw()
+                .and_then(Grid::rotate_cw)
+                .and_then(Grid::rotate_cw);
+            assert_eq!(back.unwrap().to_string(), grid.to_string());
+        });
+    }
+
+    #[test]
+    fn test_flip_and_transpose() {
+        for_each_storage(SPAN, '.', &CELLS, |grid| {
+            let flipped = |f: fn(Grid<char>) -> Result<Grid<char>, OrientOverflow>| {
+                f(grid.clone()).unwrap().to_string()
+            };
+            assert_eq!(flipped(Grid::flip_horizontal), "cba\n..d\n");
+            assert_eq!(flipped(Grid::flip_vertical), "d..\nabc\n");
+            assert_eq!(flipped(Grid::transpose), "ad\nb.\nc.\n");
+            assert_eq!(
+                flipped(|g| g.orient(Orientation::AntiTranspose)),
+                ".c\n.b\nda\n"
+            );
+        });
+    }
+
+    #[test]
+    fn test_orientations() {
+        for_each_storage(SPAN, '.', &CELLS, |grid| {
+            let mut all: Vec<String> = grid
+                .orientations()
+                .map(|o| o.unwrap().1.to_string())
+                .collect();
+            assert_eq!(all[0], grid.to_string());
+            all.sort();
+            all.dedup();
+            assert_eq!(all.len(), 8);
+        });
+    }
+
+    #[test]
+    fn test_orient_overflow() {
+        let grid: Grid<char, i8> = Grid::from_coords((0, 120).into(), (9, 120).into(), '.');
+        assert!(grid.clone().flip_vertical().is_ok());
+        assert_eq!(
+            grid.clone().rotate_cw().unwrap_err(),
+            OrientOverflow {
+                orientation: Orientation::RotateCw,
+                width: 1,
+                height: 10
+            }
+        );
+        assert!(grid.orientations().any(|o| o.is_err()));
+    }
+}
@@ -0,0 +1,294 @@
+use std::fmt::{Debug, Display};
+
+use num::PrimInt;
+
+use super::{storage::dense_index, Coordinate, Grid, Rect, Storage, StorageKind};
+
+/// Borrowed rectangular window into a [`Grid`], built with [`Grid::window`].
+///
+/// Coordinates stay those of the grid, cells outside of it read as empty.
+#[derive(Debug, Clone, Copy)]
+pub struct SubGrid<'a, T: Debug, N = i32> {
+    grid: &'a Grid<T, N>,
+    bounds: Rect<N>,
+}
+
+impl<'a, T: Debug, N: PrimInt + Debug + Display> SubGrid<'a, T, N> {
+    pub fn bounds(&self) -> Rect<N> {
+        self.bounds
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds.height()
+    }
+
+    pub fn get(&self, coord: Coordinate<N>) -> Option<&'a T> {
+        self.bounds.contains(coord).then(|| self.grid.get(coord))
+    }
+
+    /// Every cell of the window, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinate<N>, &'a T)> + 'a {
+        let grid = self.grid;
+        self.bounds.coords().map(move |c| (c, grid.get(c)))
+    }
+
+    /// Copies the window into a grid of its own with the same coordinates
+    pub fn to_grid(&self) -> Grid<T, N>
+    where
+        T: Clone,
+    {
+        let mut grid = Grid::with_storage(
+            self.bounds.min,
+            self.bounds.max,
+            self.grid.empty.clone(),
+            self.grid.storage(),
+        );
+        for (coord, val) in self.iter() {
+            grid.set(coord, val.clone());
+        }
+
+        grid
+    }
+}
+
+impl<T: Debug + Display, N: PrimInt + Debug + Display> Display for SubGrid<'_, T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (_, val)) in self.iter().enumerate() {
+            write!(f, "{}", val)?;
+            if (i + 1) % self.width() == 0 {
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Debug, N: PrimInt + Debug + Display> Grid<T, N> {
+    /// Cells on row `y` from left to right, nothing if `y` is out of bounds
+    pub fn row(&self, y: N) -> impl Iterator<Item = (Coordinate<N>, &T)> + '_ {
+        let xs = (self.start.y <= y && y <= self.end.y)
+            .then(|| num::range_inclusive(self.start.x, self.end.x));
+        xs.into_iter()
+            .flatten()
+            .map(move |x| (Coordinate::new(x, y), self.get(Coordinate::new(x, y))))
+    }
+
+    /// Cells on column `x` by increasing y, nothing if `x` is out of bounds
+    pub fn col(&self, x: N) -> impl Iterator<Item = (Coordinate<N>, &T)> + '_ {
+        let ys = (self.start.x <= x && x <= self.end.x)
+            .then(|| num::range_inclusive(self.start.y, self.end.y));
+        ys.into_iter()
+            .flatten()
+            .map(move |y| (Coordinate::new(x, y), self.get(Coordinate::new(x, y))))
+    }
+
+    /// Mutable [`Grid::row`], a sparse grid fills the row with empty cells first
+    pub fn row_mut(&mut self, y: N) -> impl Iterator<Item = (Coordinate<N>, &mut T)> + '_
+    where
+        T: Clone,
+    {
+        let (start, end, width) = (self.start, self.end, self.width);
+        let inside = start.y <= y && y <= end.y;
+        if inside {
+            self.fill_sparse(Rect::new(start.with_y(y), end.with_y(y)));
+        }
+
+        let cells = match &mut self.points {
+            _ if !inside => None,
+            Storage::Sparse(points) => Some(Either::Sparse(
+                points
+                    .iter_mut()
+                    .filter(move |(c, _)| c.y == y && start.x <= c.x && c.x <= end.x)
+                    .map(|(c, v)| (*c, v)),
+            )),
+            Storage::Dense(points) => {
+                let first = dense_index(start, width, start.with_y(y));
+                Some(Either::Dense(
+                    points[first..first + width]
+                        .iter_mut()
+                        .zip(num::range_inclusive(start.x, end.x))
+                        .map(move |(v, x)| (Coordinate::new(x, y), v)),
+                ))
+            }
+        };
+        cells.into_iter().flatten()
+    }
+
+    /// Mutable [`Grid::col`], a sparse grid fills the column with empty cells first
+    pub fn col_mut(&mut self, x: N) -> impl Iterator<Item = (Coordinate<N>, &mut T)> + '_
+    where
+        T: Clone,
+    {
+        let (start, end, width) = (self.start, self.end, self.width);
+        let inside = start.x <= x && x <= end.x;
+        if inside {
+            self.fill_sparse(Rect::new(start.with_x(x), end.with_x(x)));
+        }
+
+        let cells = match &mut self.points {
+            _ if !inside => None,
+            Storage::Sparse(points) => Some(Either::Sparse(
+                points
+                    .range_mut(start.with_x(x)..=end.with_x(x))
+                    .map(|(c, v)| (*c, v)),
+            )),
+            Storage::Dense(points) => {
+                let first = dense_index(start, width, start.with_x(x));
+                Some(Either::Dense(
+                    points[first..]
+                        .iter_mut()
+                        .step_by(width)
+                        .zip(num::range_inclusive(start.y, end.y))
+                        .map(move |(v, y)| (Coordinate::new(x, y), v)),
+                ))
+            }
+        };
+        cells.into_iter().flatten()
+    }
+
+    /// Stores empty cells for everything in `rect` a sparse grid doesn't have yet
+    pub(super) fn fill_sparse(&mut self, rect: Rect<N>)
+    where
+        T: Clone,
+    {
+        if self.storage() != StorageKind::Sparse {
+            return;
+        }
+        for coord in rect.coords() {
+            if let Storage::Sparse(points) = &mut self.points {
+                points.entry(coord).or_insert_with(|| self.empty.clone());
+            }
+        }
+    }
+
+    /// Read only view of the cells inside `bounds`
+    pub fn window(&self, bounds: Rect<N>) -> SubGrid<'_, T, N> {
+        SubGrid { grid: self, bounds }
+    }
+
+    /// Every `size` by `size` window that fits inside the grid, in reading order of their top
+    /// left corner
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = SubGrid<'_, T, N>> + '_ {
+        let fits = size > 0 && size <= self.width && size <= self.height;
+        let span = N::from(size.saturating_sub(1)).unwrap();
+        let corners = fits.then(|| Rect::new(self.start, self.end - Coordinate::new(span, span)));
+
+        corners
+            .into_iter()
+            .flat_map(|r| r.coords())
+            .map(move |min| self.window(Rect::new(min, min + Coordinate::new(span, span))))
+    }
+}
+
+/// Iterator over either storage, so both branches share one return type
+enum Either<S, D> {
+    Sparse(S),
+    Dense(D),
+}
+
+impl<S: Iterator, D: Iterator<Item = S::Item>> Iterator for Either<S, D> {
+    type Item = S::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Either::Sparse(cells) => cells.next(),
+            Either::Dense(cells) => cells.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Either::Sparse(cells) => cells.size_hint(),
+            Either::Dense(cells) => cells.size_hint(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::grid::for_each_storage;
+
+    const SPAN: ((i32, i32), (i32, i32)) = ((0, 0), (2, 2));
+    /// Cells numbered from 1, row by row
+    const CELLS: [((i32, i32), u32); 9] = [
+        ((0, 0), 1),
+        ((1, 0), 2),
+        ((2, 0), 3),
+        ((0, 1), 4),
+        ((1, 1), 5),
+        ((2, 1), 6),
+        ((0, 2), 7),
+        ((1, 2), 8),
+        ((2, 2), 9),
+    ];
+
+    #[test]
+    fn test_row_col() {
+        for_each_storage(SPAN, 0, &CELLS, |grid| {
+            let row: Vec<u32> = grid.row(1).map(|(_, v)| *v).collect();
+            assert_eq!(row, vec![4, 5, 6]);
+            let col: Vec<_> = grid.col(2).collect();
+            assert_eq!(
+                col,
+                vec![
+                    ((2, 0).into(), &3),
+                    ((2, 1).into(), &6),
+                    ((2, 2).into(), &9)
+                ]
+            );
+            assert_eq!(grid.row(3).count(), 0);
+            assert_eq!(grid.col(-1).count(), 0);
+        });
+    }
+
+    #[test]
+    fn test_row_col_mut() {
+        for_each_storage(((0, 0), (2, 1)), 0u32, &[], |mut grid| {
+            for (coord, val) in grid.row_mut(1) {
+                *val = coord.x as u32 + 1;
+            }
+            for (_, val) in grid.col_mut(1) {
+                *val += 10;
+            }
+            assert_eq!(grid.to_string(), "0100\n1123\n", "{:?}", grid.storage());
+            assert_eq!(grid.row_mut(2).count(), 0);
+        });
+    }
+
+    #[test]
+    fn test_window() {
+        for_each_storage(SPAN, 0, &CELLS, |grid| {
+            let window = grid.window(Rect::new((1, 1).into(), (2, 3).into()));
+            assert_eq!((window.width(), window.height()), (2, 3));
+            assert_eq!(window.get((1, 1).into()), Some(&5));
+            assert_eq!(window.get((0, 0).into()), None);
+            assert_eq!(window.get((2, 3).into()), Some(&0));
+            assert_eq!(window.to_string(), "56\n89\n00\n");
+
+            let copy = window.to_grid();
+            assert_eq!(copy.bounds(), window.bounds());
+            assert_eq!(*copy.get((2, 2).into()), 9);
+        });
+    }
+
+    #[test]
+    fn test_windows() {
+        for_each_storage(SPAN, 0, &CELLS, |grid| {
+            let sums: Vec<u32> = grid
+                .windows(2)
+                .map(|w| w.iter().map(|(_, v)| v).sum())
+                .collect();
+            assert_eq!(sums, vec![12, 16, 24, 28]);
+            assert_eq!(grid.windows(3).count(), 1);
+            assert_eq!(grid.windows(4).count(), 0);
+            assert_eq!(grid.windows(0).count(), 0);
+        });
+    }
+}
@@ -0,0 +1,269 @@
+//! Sets of integers stored as sorted, disjoint inclusive ranges.
+//!
+//! Coverage puzzles can then be answered with range arithmetic instead of
+//! painting every single cell into a grid.
+
+use std::{fmt::Display, ops::RangeInclusive};
+
+use num::PrimInt;
+
+/// Set of integers kept as sorted ranges that neither overlap nor touch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<N> {
+    ranges: Vec<(N, N)>,
+}
+
+impl<N> Default for IntervalSet<N> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<N: PrimInt> IntervalSet<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value in `range`, merging it with whatever it overlaps or touches
+    pub fn insert(&mut self, range: RangeInclusive<N>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // Ranges entirely before the new one, they don't touch it
+        let before = self
+            .ranges
+            .partition_point(|(_, e)| after(*e).is_some_and(|a| a < start));
+        // Ranges up to here touch or overlap the new one
+        let mut last = before;
+        while last < self.ranges.len() && after(end).is_none_or(|a| self.ranges[last].0 <= a) {
+            start = start.min(self.ranges[last].0);
+            end = end.max(self.ranges[last].1);
+            last += 1;
+        }
+        self.ranges.splice(before..last, [(start, end)]);
+    }
+
+    /// Removes every value in `range`
+    pub fn remove(&mut self, range: RangeInclusive<N>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        for &(s, e) in &self.ranges {
+            if e < start || s > end {
+                ranges.push((s, e));
+                continue;
+            }
+            if s < start {
+                ranges.push((s, start - N::one()));
+            }
+            if e > end {
+                ranges.push((end + N::one(), e));
+            }
+        }
+        self.ranges = ranges;
+    }
+
+    pub fn contains(&self, value: N) -> bool {
+        let i = self.ranges.partition_point(|(_, e)| *e < value);
+        self.ranges.get(i).is_some_and(|(s, _)| *s <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of values covered by the set, counted in `u128` so the whole range of `N` fits.
+    /// Only the whole range of a 128 bit type doesn't, it saturates at `u128::MAX`.
+    pub fn len(&self) -> u128 {
+        self.ranges
+            .iter()
+            .fold(0, |acc: u128, (s, e)| acc.saturating_add(count(*s, *e)))
+    }
+
+    /// Smallest and largest value in the set
+    pub fn span(&self) -> Option<RangeInclusive<N>> {
+        let first = self.ranges.first()?;
+        let last = self.ranges.last()?;
+
+        Some(first.0..=last.1)
+    }
+
+    /// The merged ranges, in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<N>> + '_ {
+        self.ranges.iter().map(|(s, e)| *s..=*e)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.insert(range);
+        }
+
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (self.ranges[i], other.ranges[j]);
+            let start = a.0.max(b.0);
+            let end = a.1.min(b.1);
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.remove(range);
+        }
+
+        result
+    }
+
+    /// Values inside `within` that are missing from the set, as ranges
+    pub fn gaps(&self, within: RangeInclusive<N>) -> Self {
+        Self::from(within).difference(self)
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.difference(self).is_empty()
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+}
+
+/// Number of values in `start..=end`, saturating like [`IntervalSet::len`]
+fn count<N: PrimInt>(start: N, end: N) -> u128 {
+    let diff = match (start.to_i128(), end.to_i128()) {
+        // Two's complement wrapping gives the exact distance, it's never above u128::MAX
+        (Some(s), Some(e)) => (e as u128).wrapping_sub(s as u128),
+        // Only unsigned values go past i128::MAX
+        _ => end.to_u128().unwrap() - start.to_u128().unwrap(),
+    };
+
+    diff.saturating_add(1)
+}
+
+/// Value right after `n`, `None` when `n` is the largest value of its type
+fn after<N: PrimInt>(n: N) -> Option<N> {
+    n.checked_add(&N::one())
+}
+
+impl<N: PrimInt> From<RangeInclusive<N>> for IntervalSet<N> {
+    fn from(range: RangeInclusive<N>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+
+        set
+    }
+}
+
+impl<N: PrimInt> FromIterator<RangeInclusive<N>> for IntervalSet<N> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<N>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+
+        set
+    }
+}
+
+impl<N: PrimInt> Extend<RangeInclusive<N>> for IntervalSet<N> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<N>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<N: Display> Display for IntervalSet<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (i, (s, e)) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}..={}", s, e)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i64>]) -> IntervalSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let s = set(&[5..=7, 1..=2, 3..=3, 10..=12, 6..=11]);
+        assert_eq!(s.iter().collect::<Vec<_>>(), vec![1..=3, 5..=12]);
+        assert_eq!(s.len(), 11);
+        assert_eq!(s.span(), Some(1..=12));
+        assert!(s.contains(3) && s.contains(5) && !s.contains(4) && !s.contains(13));
+        assert_eq!(s.to_string(), "{1..=3, 5..=12}");
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[0..=10, 20..=30]);
+        let b = set(&[5..=25]);
+
+        assert_eq!(a.union(&b), set(&[0..=30]));
+        assert_eq!(a.intersection(&b), set(&[5..=10, 20..=25]));
+        assert_eq!(a.difference(&b), set(&[0..=4, 26..=30]));
+        assert_eq!(b.difference(&a), set(&[11..=19]));
+        assert_eq!(a.gaps(-5..=35), set(&[-5..=-1, 11..=19, 31..=35]));
+        assert!(a.is_superset(&set(&[2..=4, 21..=21])));
+        assert!(!a.is_superset(&b));
+        assert!(a.intersects(&b));
+        assert!(!a.intersects(&set(&[11..=19])));
+    }
+
+    #[test]
+    fn test_extremes() {
+        let mut s: IntervalSet<u8> = IntervalSet::from(250..=255);
+        s.insert(0..=3);
+        s.insert(4..=4);
+        assert_eq!(s.iter().collect::<Vec<_>>(), vec![0..=4, 250..=255]);
+        s.remove(0..=0);
+        s.remove(255..=255);
+        assert_eq!(s.iter().collect::<Vec<_>>(), vec![1..=4, 250..=254]);
+        assert_eq!(s.gaps(0..=255).len(), 247);
+    }
+
+    #[test]
+    fn test_len_full_range() {
+        assert_eq!(IntervalSet::from(0..=u8::MAX).len(), 256);
+        assert_eq!(IntervalSet::from(i64::MIN..=i64::MAX).len(), 1 << 64);
+        assert_eq!(IntervalSet::from(u64::MAX - 1..=u64::MAX).len(), 2);
+        assert_eq!(IntervalSet::from(0..=u128::MAX).len(), u128::MAX);
+        assert_eq!(IntervalSet::from(i128::MIN..=i128::MAX).len(), u128::MAX);
+
+        let mut s = IntervalSet::from(i8::MIN..=-1);
+        s.insert(1..=i8::MAX);
+        assert_eq!(s.len(), 255);
+    }
+}
@@ -1,4 +1,7 @@
+pub mod automaton;
 pub mod grid;
+pub mod interval;
+pub mod search;
 
 pub fn add(left: usize, right: usize) -> usize {
     left + right
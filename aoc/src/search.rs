@@ -0,0 +1,300 @@
+//! Shortest path searches over anything that can list its neighbors.
+//!
+//! Nodes are plain values (usually a [`Coordinate`](crate::grid::Coordinate)), the graph is
+//! described by closures so a grid, a map or a generated state space all work the same way.
+//! Every search accepts several sources and stops at the first node matching `is_target`.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use num::Zero;
+
+/// Result of a successful search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    /// Total cost from the source to the target
+    pub cost: C,
+    /// Every node visited, source first and target last
+    pub nodes: Vec<N>,
+}
+
+/// Breadth first search where every step costs 1
+pub fn bfs<N, I, FN, FT>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbors: FN,
+    mut is_target: FT,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> I,
+    FT: FnMut(&N) -> bool,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for source in sources {
+        if parents.insert(source.clone(), None).is_none() {
+            queue.push_back((source, 0));
+        }
+    }
+
+    while let Some((node, cost)) = queue.pop_front() {
+        if is_target(&node) {
+            return Some(Path {
+                cost,
+                nodes: reconstruct(&parents, node),
+            });
+        }
+        for next in neighbors(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm, `cost(from, to)` is the price of a single step
+pub fn dijkstra<N, C, I, FN, FC, FT>(
+    sources: impl IntoIterator<Item = N>,
+    neighbors: FN,
+    cost: FC,
+    is_target: FT,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> I,
+    FC: FnMut(&N, &N) -> C,
+    FT: FnMut(&N) -> bool,
+{
+    astar(sources, neighbors, cost, |_| C::zero(), is_target)
+}
+
+/// A* search, `heuristic` must never overestimate the remaining cost to a target
+pub fn astar<N, C, I, FN, FC, FH, FT>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbors: FN,
+    mut cost: FC,
+    mut heuristic: FH,
+    mut is_target: FT,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> I,
+    FC: FnMut(&N, &N) -> C,
+    FH: FnMut(&N) -> C,
+    FT: FnMut(&N) -> bool,
+{
+    let mut best: HashMap<N, (C, Option<N>)> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    for source in sources {
+        best.insert(source.clone(), (C::zero(), None));
+        heap.push(State {
+            priority: heuristic(&source),
+            cost: C::zero(),
+            node: source,
+        });
+    }
+
+    while let Some(State {
+        cost: so_far, node, ..
+    }) = heap.pop()
+    {
+        if best.get(&node).is_some_and(|(c, _)| *c < so_far) {
+            // Stale entry, a cheaper way here was already expanded
+            continue;
+        }
+        if is_target(&node) {
+            let parents = best.into_iter().map(|(n, (_, p))| (n, p)).collect();
+            return Some(Path {
+                cost: so_far,
+                nodes: reconstruct(&parents, node),
+            });
+        }
+        for next in neighbors(&node) {
+            let next_cost = so_far + cost(&node, &next);
+            if best.get(&next).is_none_or(|(c, _)| next_cost < *c) {
+                best.insert(next.clone(), (next_cost, Some(node.clone())));
+                heap.push(State {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+fn reconstruct<N: Clone + Eq + Hash>(parents: &HashMap<N, Option<N>>, target: N) -> Vec<N> {
+    let mut nodes = vec![target];
+    while let Some(Some(parent)) = parents.get(nodes.last().unwrap()) {
+        nodes.push(parent.clone());
+    }
+    nodes.reverse();
+
+    nodes
+}
+
+/// Heap entry ordered so the lowest priority is popped first
+struct State<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for State<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for State<N, C> {}
+
+impl<N, C: Ord> PartialOrd for State<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for State<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Coordinate, Grid, DIRECTIONS};
+
+    const MAZE: &str = "S.#.....\n.##.###.\n....#E..\n.##.##..";
+
+    fn maze() -> (Grid<bool>, Coordinate, Coordinate) {
+        let (grid, markers) = Grid::parse(MAZE, false, &['S', 'E'], |c| c != '#').unwrap();
+        (grid, markers.get('S').unwrap(), markers.get('E').unwrap())
+    }
+
+    fn open_neighbors(grid: &Grid<bool>, coord: Coordinate) -> Vec<Coordinate> {
+        DIRECTIONS
+            .iter()
+            .map(|dir| coord.offset_direction(*dir, 1))
+            .filter(|c| matches!(grid.get_bounded(*c), Ok(true)))
+            .collect()
+    }
+
+    fn assert_connected(path: &[Coordinate]) {
+        for pair in path.windows(2) {
+            assert_eq!(pair[0].manhattan_distance(pair[1]), 1);
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (grid, start, end) = maze();
+        let path = bfs([start], |c| open_neighbors(&grid, *c), |c| *c == end).unwrap();
+
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.nodes.len(), 16);
+        assert_eq!(path.nodes.first(), Some(&start));
+        assert_eq!(path.nodes.last(), Some(&end));
+        assert_connected(&path.nodes);
+    }
+
+    #[test]
+    fn test_dijkstra_matches_bfs() {
+        let (grid, start, end) = maze();
+        let path = dijkstra(
+            [start],
+            |c| open_neighbors(&grid, *c),
+            |_, _| 1u32,
+            |c| *c == end,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 15);
+        assert_connected(&path.nodes);
+    }
+
+    #[test]
+    fn test_dijkstra_weighted() {
+        // 0 -> 1 -> 3 costs 2, 0 -> 2 -> 3 costs 11, 0 -> 3 costs 5
+        let edges = |n: &u8| -> Vec<(u8, u32)> {
+            match n {
+                0 => vec![(1, 1), (2, 1), (3, 5)],
+                1 => vec![(3, 1)],
+                2 => vec![(3, 10)],
+                _ => vec![],
+            }
+        };
+        let path = dijkstra(
+            [0],
+            |n| edges(n).into_iter().map(|(n, _)| n),
+            |from, to| edges(from).into_iter().find(|(n, _)| n == to).unwrap().1,
+            |n| *n == 3,
+        )
+        .unwrap();
+
+        assert_eq!(
+            path,
+            Path {
+                cost: 2,
+                nodes: vec![0, 1, 3]
+            }
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let (grid, start, end) = maze();
+        let path = astar(
+            [start],
+            |c| open_neighbors(&grid, *c),
+            |_, _| 1,
+            |c| c.manhattan_distance(end),
+            |c| *c == end,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 15);
+        assert_connected(&path.nodes);
+    }
+
+    #[test]
+    fn test_multiple_sources_and_targets() {
+        let (grid, start, end) = maze();
+        let sources = [start, Coordinate::new(7, 0)];
+        let targets = [end, Coordinate::new(0, 3)];
+        let path = bfs(
+            sources,
+            |c| open_neighbors(&grid, *c),
+            |c| targets.contains(c),
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes.first(), Some(&start));
+        assert_eq!(path.nodes.last(), Some(&Coordinate::new(0, 3)));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let (grid, start, _) = maze();
+        let walled = Coordinate::new(2, 0);
+        assert_eq!(
+            bfs([start], |c| open_neighbors(&grid, *c), |c| *c == walled),
+            None
+        );
+    }
+}
@@ -0,0 +1,217 @@
+//! Accepted answers of the solved days, to catch a solution that silently changes.
+//!
+//! The file has one `day part answer` line per part. Backslashes and line breaks in answers
+//! are escaped, lines starting with `#` are comments.
+
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path};
+
+/// Where the runner keeps its answers, relative to the workspace root
+pub const ANSWERS_FILE: &str = "src/answers.txt";
+
+/// How an answer compares to the recorded one
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Verdict {
+    Pass,
+    /// A different answer, or none at all, for a part with a recorded answer
+    Fail {
+        expected: String,
+    },
+    /// Nothing recorded for the part. A part skipped for lack of a solution or input is
+    /// never checked, so it's left unknown too
+    Unknown,
+}
+
+impl Verdict {
+    pub fn is_fail(&self) -> bool {
+        matches!(self, Verdict::Fail { .. })
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail { .. } => "fail",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseAnswersError {
+    pub line: usize,
+}
+
+impl Display for ParseAnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {} of the answers isn't `day part answer`",
+            self.line
+        )
+    }
+}
+
+impl std::error::Error for ParseAnswersError {}
+
+/// Recorded answer of every day and part
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut out = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                out.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                out.push('\\');
+                chars.next();
+            }
+            (c, _) => out.push(c),
+        }
+    }
+    out
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, ParseAnswersError> {
+        let mut answers = BTreeMap::new();
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = ParseAnswersError { line: i + 1 };
+            let mut fields = line.splitn(3, ' ');
+            let mut number = || fields.next()?.parse::<u8>().ok();
+            let (day, part) = (
+                number().ok_or(error.clone())?,
+                number().ok_or(error.clone())?,
+            );
+            let answer = fields.next().ok_or(error)?;
+            answers.insert((day, part), unescape(answer));
+        }
+
+        Ok(Self { answers })
+    }
+
+    /// Answers stored at `path`, none when there's no such file
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                Self::parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Stores `answer` as the accepted one, replacing what was there
+    pub fn record(&mut self, day: u8, part: u8, answer: impl Into<String>) {
+        self.answers.insert((day, part), answer.into());
+    }
+
+    /// Compares what a part came up with to its recorded answer
+    pub fn check(&self, day: u8, part: u8, answer: Option<&str>) -> Verdict {
+        match (self.get(day, part), answer) {
+            (None, _) => Verdict::Unknown,
+            (Some(expected), Some(answer)) if expected == answer => Verdict::Pass,
+            (Some(expected), _) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day part answer")?;
+        for ((day, part), answer) in &self.answers {
+            writeln!(f, "{} {} {}", day, part, escape(answer))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.record(14, 1, "24");
+        answers.record(1, 2, "45000");
+        answers.record(10, 2, "#.\\\n.#");
+
+        let text = answers.to_string();
+        assert_eq!(
+            text,
+            "# day part answer\n1 2 45000\n10 2 #.\\\\\\n.#\n14 1 24\n"
+        );
+        assert_eq!(Answers::parse(&text), Ok(answers));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Answers::parse("1 1 ok\n\n2 x 3"),
+            Err(ParseAnswersError { line: 3 })
+        );
+        assert_eq!(
+            Answers::parse("1 1").unwrap_err().to_string(),
+            "line 1 of the answers isn't `day part answer`"
+        );
+        assert_eq!(Answers::parse("1 1 a b").unwrap().get(1, 1), Some("a b"));
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("5 1 CMZ").unwrap();
+        assert_eq!(answers.check(5, 1, Some("CMZ")), Verdict::Pass);
+        assert_eq!(
+            answers.check(5, 1, Some("MCD")),
+            Verdict::Fail {
+                expected: "CMZ".to_string()
+            }
+        );
+        assert!(answers.check(5, 1, None).is_fail());
+        assert_eq!(answers.check(5, 2, Some("MCD")), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let answers = Answers::load("src/no_such_answers.txt").unwrap();
+        assert!(answers.is_empty());
+    }
+}
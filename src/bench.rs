@@ -0,0 +1,187 @@
+//! Repeated timing of a solution, for numbers that mean something at microsecond scale.
+//!
+//! Only measures, printing is left to the caller.
+
+use std::{fmt::Display, hint::black_box, time::Duration, time::Instant};
+
+/// Calls `f` once, returns its result with the time it took
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let timer = Instant::now();
+    let result = f();
+
+    (result, timer.elapsed())
+}
+
+/// Summary of the time every measured run took
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Stats of `samples`, `None` without any
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let runs = sorted.len();
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / runs as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        Some(Self {
+            runs,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min: {:.2?}, median: {:.2?}, mean: {:.2?} ± {:.2?}, {} runs",
+            self.min, self.median, self.mean, self.stddev, self.runs
+        )
+    }
+}
+
+/// How long to warm up and measure for.
+///
+/// After warming up, the time a run took so far picks how many runs fit in the measuring
+/// time, kept between the minimum and maximum number of runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bench {
+    warmup: Duration,
+    measure: Duration,
+    min_runs: usize,
+    max_runs: usize,
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(100),
+            measure: Duration::from_millis(500),
+            min_runs: 5,
+            max_runs: 100_000,
+        }
+    }
+}
+
+impl Bench {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Time spent running before measuring, there's always at least one warmup run
+    pub fn warmup(mut self, warmup: Duration) -> Self {
+        self.warmup = warmup;
+        self
+    }
+
+    /// Time to aim for while measuring
+    pub fn measure(mut self, measure: Duration) -> Self {
+        self.measure = measure;
+        self
+    }
+
+    /// Bounds on the number of measured runs, whatever the time they take.
+    ///
+    /// # Panics
+    ///
+    /// If `min` is 0 or larger than `max`.
+    pub fn runs(mut self, min: usize, max: usize) -> Self {
+        assert!(
+            min > 0 && min <= max,
+            "invalid number of runs {min}..={max}"
+        );
+        self.min_runs = min;
+        self.max_runs = max;
+        self
+    }
+
+    /// Warms up on `f`, then times it as many times as fit in the measuring time
+    pub fn run<T>(&self, mut f: impl FnMut() -> T) -> Stats {
+        let start = Instant::now();
+        let mut warmups = 0;
+        while warmups == 0 || start.elapsed() < self.warmup {
+            black_box(f());
+            warmups += 1;
+        }
+        let estimate = start.elapsed().as_secs_f64() / warmups as f64;
+
+        let fit = (self.measure.as_secs_f64() / estimate.max(1e-9)) as usize;
+        let runs = fit.clamp(self.min_runs, self.max_runs);
+        let samples: Vec<Duration> = (0..runs)
+            .map(|_| {
+                let (result, elapsed) = timed(&mut f);
+                black_box(result);
+                elapsed
+            })
+            .collect();
+
+        Stats::new(&samples).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|s| Duration::from_micros(*s)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(&micros(&[4, 2, 9, 5, 4, 4, 7, 5])).unwrap();
+        assert_eq!(stats.runs, 8);
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(
+            stats.median,
+            Duration::from_micros(4) + Duration::from_nanos(500)
+        );
+        assert_eq!(stats.mean.as_nanos(), 5000);
+        assert_eq!(stats.stddev.as_nanos(), 2000);
+
+        let odd = Stats::new(&micros(&[3, 1, 2])).unwrap();
+        assert_eq!(odd.median, Duration::from_micros(2));
+        assert_eq!(Stats::new(&[]), None);
+    }
+
+    #[test]
+    fn test_bench() {
+        let mut calls = 0;
+        let stats = Bench::new()
+            .warmup(Duration::ZERO)
+            .measure(Duration::ZERO)
+            .runs(3, 10)
+            .run(|| calls += 1);
+        assert_eq!(stats.runs, 3);
+        assert_eq!(calls, 4);
+        assert!(stats.min <= stats.median);
+
+        let stats = Bench::new()
+            .warmup(Duration::from_millis(1))
+            .measure(Duration::from_secs(1))
+            .runs(1, 50)
+            .run(|| black_box(1 + 1));
+        assert_eq!(stats.runs, 50);
+    }
+}
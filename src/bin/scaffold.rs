@@ -16,25 +16,19 @@ pub fn part_two(input: &str) -> Option<u32> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
+        let input = crate::read_file("examples", DAY);
         assert_eq!(part_one(&input), None);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
+        let input = crate::read_file("examples", DAY);
         assert_eq!(part_two(&input), None);
     }
 }
@@ -50,7 +44,11 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
 fn main() {
@@ -66,7 +64,7 @@ fn main() {
 
     let input_path = format!("src/inputs/{}.txt", day_padded);
     let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let module_path = format!("src/days/day{}.rs", day_padded);
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -107,6 +105,10 @@ fn main() {
     }
 
     println!("---");
+    println!(
+        "🎄 Add `{} => day{},` to the `register!` list in \"src/days/mod.rs\".",
+        day, &day_padded
+    );
     println!(
         "🎄 Type `cargo solve {}` to run your solution.",
         &day_padded
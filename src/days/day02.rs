@@ -132,7 +132,7 @@ impl Game {
 }
 
 fn parse_line(input: &str) -> Game {
-    let mut parts = input.split(' ').into_iter();
+    let mut parts = input.split(' ');
     let opponent = parts.next().unwrap().parse().unwrap();
     let player = parts.next().unwrap().parse().unwrap();
 
@@ -157,25 +157,19 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(total)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = crate::read_file("examples", 2);
         assert_eq!(part_one(&input), Some(15));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = crate::read_file("examples", 2);
         assert_eq!(part_two(&input), Some(12));
     }
 }
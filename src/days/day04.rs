@@ -0,0 +1,59 @@
+use aoc::interval::IntervalSet;
+
+fn segment_range(segment: &str) -> IntervalSet<u32> {
+    let (start, finish) = segment.split_once('-').unwrap();
+    let start = start.parse().unwrap();
+    let finish = finish.parse().unwrap();
+
+    IntervalSet::from(start..=finish)
+}
+
+fn parse_line(line: &str) -> (IntervalSet<u32>, IntervalSet<u32>) {
+    let (a, b) = line.split_once(',').unwrap();
+
+    (segment_range(a), segment_range(b))
+}
+
+/// Whether a completely contains b
+fn range_contains_completely(a: &IntervalSet<u32>, b: &IntervalSet<u32>) -> bool {
+    a.is_superset(b) || b.is_superset(a)
+}
+
+fn range_contains_at_all(a: &IntervalSet<u32>, b: &IntervalSet<u32>) -> bool {
+    a.intersects(b)
+}
+
+pub fn common(input: &str, f: fn(&IntervalSet<u32>, &IntervalSet<u32>) -> bool) -> u32 {
+    input.lines().fold(0, |acc, line| {
+        let (a, b) = parse_line(line);
+        if f(&a, &b) {
+            acc + 1
+        } else {
+            acc
+        }
+    })
+}
+pub fn part_one(input: &str) -> Option<u32> {
+    Some(common(input, range_contains_completely))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Some(common(input, range_contains_at_all))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 4);
+        assert_eq!(part_one(&input), Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 4);
+        assert_eq!(part_two(&input), Some(4));
+    }
+}
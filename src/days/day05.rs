@@ -32,7 +32,7 @@ impl Crates {
         while num > 0 {
             let item = self.stacks[start]
                 .pop()
-                .expect(&format!("No stack with {num}, {start}, {end}: {self:?}"));
+                .unwrap_or_else(|| panic!("No stack with {num}, {start}, {end}: {self:?}"));
             let dest_col = &mut self.stacks[end];
 
             dest_col.push(item);
@@ -46,7 +46,7 @@ impl Crates {
         while num > 0 {
             let item = self.stacks[start]
                 .pop()
-                .expect(&format!("No stack with {num}, {start}, {end}: {self:?}"));
+                .unwrap_or_else(|| panic!("No stack with {num}, {start}, {end}: {self:?}"));
             new.push(item);
             num -= 1;
         }
@@ -101,25 +101,19 @@ pub fn part_two(input: &str) -> Option<String> {
     Some(crates.end())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = crate::read_file("examples", 5);
         assert_eq!(part_one(&input), Some("CMZ".to_string()));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = crate::read_file("examples", 5);
         assert_eq!(part_two(&input), Some("MCD".to_string()));
     }
 }
@@ -29,19 +29,13 @@ pub fn part_two(input: &str) -> Option<u32> {
     distinct(input, 14)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 6);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 6);
+        let input = crate::read_file("examples", 6);
         let mut lines = input.lines();
         assert_eq!(part_one(lines.next().unwrap()), Some(7));
         assert_eq!(part_one(lines.next().unwrap()), Some(5));
@@ -52,7 +46,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 6);
+        let input = crate::read_file("examples", 6);
         let mut lines = input.lines();
         assert_eq!(part_two(lines.next().unwrap()), Some(19));
         assert_eq!(part_two(lines.next().unwrap()), Some(23));
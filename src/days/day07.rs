@@ -169,7 +169,7 @@ fn traverse(input: &str) -> Nodes {
                     cwd = fs
                         .get_dir_ref(cwd)
                         .parent
-                        .expect(&format!("No parent for {cwd:?}"))
+                        .unwrap_or_else(|| panic!("No parent for {cwd:?}"))
                 }
                 _ => cwd = fs.find_by_name(cwd, &dir).unwrap(),
             },
@@ -212,35 +212,24 @@ pub fn part_two(input: &str) -> Option<u64> {
         .iter()
         .filter_map(|dir| {
             let size = fs.size(dir.id);
-            if size >= needed {
-                println!("{size}");
-                Some(size)
-            } else {
-                None
-            }
+            (size >= needed).then_some(size)
         })
         .min()
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = crate::read_file("examples", 7);
         assert_eq!(part_one(&input), Some(95437));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = crate::read_file("examples", 7);
         assert_eq!(part_two(&input), Some(24933642));
     }
 }
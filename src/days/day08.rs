@@ -0,0 +1,62 @@
+use aoc::grid::{Coordinate, Grid, DIRECTIONS};
+
+fn parse_grid(input: &str) -> Grid<u8> {
+    let (grid, _) = Grid::parse(input, 0, &[], |char| char.to_digit(10).unwrap() as u8).unwrap();
+
+    grid
+}
+
+fn is_visible(grid: &Grid<u8>, coord: Coordinate) -> bool {
+    let tree = *grid.get(coord);
+    DIRECTIONS
+        .into_iter()
+        .any(|dir| grid.ray(coord, dir).all(|(_, t)| *t < tree))
+}
+
+fn scenic_score(grid: &Grid<u8>, coord: Coordinate) -> u32 {
+    let tree = *grid.get(coord);
+    DIRECTIONS
+        .into_iter()
+        .map(|dir| match grid.walk_until(coord, dir, |t| *t >= tree) {
+            Some((blocker, _)) => blocker.manhattan_distance(coord) as u32,
+            None => grid.ray(coord, dir).count() as u32,
+        })
+        .product()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid = parse_grid(input);
+
+    Some(
+        grid.coords()
+            .into_iter()
+            .filter(|c| is_visible(&grid, *c))
+            .count() as u32,
+    )
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid = parse_grid(input);
+
+    grid.coords()
+        .into_iter()
+        .map(|c| scenic_score(&grid, c))
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 8);
+        assert_eq!(part_one(&input), Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 8);
+        assert_eq!(part_two(&input), Some(8));
+    }
+}
@@ -1,5 +1,9 @@
+use aoc::grid::Direction;
 use nom::{
-    bytes::complete::tag, character::complete::one_of, combinator::map, sequence::separated_pair,
+    bytes::complete::tag,
+    character::complete::one_of,
+    combinator::{map, map_res},
+    sequence::separated_pair,
     IResult,
 };
 use std::{
@@ -16,12 +20,9 @@ struct Coord {
 
 impl Coord {
     fn move_direction(&mut self, direction: Direction) {
-        match direction {
-            Direction::Left => self.x -= 1,
-            Direction::Right => self.x += 1,
-            Direction::Up => self.y += 1,
-            Direction::Down => self.y -= 1,
-        }
+        let step = direction.to_offset();
+        self.x += step.x;
+        self.y += step.y;
     }
 
     fn move_closest(&mut self, other: Coord) {
@@ -80,22 +81,8 @@ impl From<(i32, i32)> for Coord {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    Left,
-    Right,
-    Up,
-    Down,
-}
-
 fn parse_direction(i: &str) -> IResult<&str, Direction> {
-    map(one_of("LRUD"), |char| match char {
-        'L' => Direction::Left,
-        'R' => Direction::Right,
-        'U' => Direction::Up,
-        'D' => Direction::Down,
-        _ => unreachable!(),
-    })(i)
+    map_res(one_of("LRUD"), Direction::try_from)(i)
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -133,13 +120,13 @@ impl Grid {
                 .unwrap()
                 .move_direction(delta.direction);
 
-            let mut prev = self.snake[0].clone();
+            let mut prev = self.snake[0];
 
             for coord in self.snake[1..].iter_mut() {
                 if prev.abs_diff(*coord) > 1 {
                     coord.move_closest(prev);
                 }
-                prev = coord.clone();
+                prev = *coord;
             }
 
             self.visited.insert(*self.snake.last().unwrap());
@@ -164,12 +151,6 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(snek(input, 8))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 9);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,13 +166,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 9);
+        let input = crate::read_file("examples", 9);
         assert_eq!(part_one(&input), Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 9);
+        let input = crate::read_file("examples", 9);
         assert_eq!(part_two(&input), Some(1));
     }
 
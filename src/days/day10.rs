@@ -1,6 +1,6 @@
 use std::{collections::VecDeque, fmt::Display};
 
-use itertools::Itertools;
+use aoc::grid::{Coordinate, Grid};
 use nom::{branch::alt, bytes::complete::tag, combinator::map, sequence::preceded, IResult};
 
 #[derive(Debug, Clone, Copy)]
@@ -45,7 +45,7 @@ struct Cpu {
     instructions: VecDeque<Instruction>,
     pending: Option<Instruction>,
     until_execute: u32,
-    crt: Vec<bool>,
+    crt: Grid<bool>,
 }
 
 #[derive(Debug)]
@@ -65,7 +65,7 @@ impl Display for Cpu {
 }
 
 impl Cpu {
-    const WIDTH: usize = 40;
+    const WIDTH: i32 = 40;
 
     fn new(instructions: VecDeque<Instruction>) -> Self {
         Self {
@@ -74,14 +74,17 @@ impl Cpu {
             instructions,
             until_execute: 0,
             pending: None,
-            crt: Vec::new(),
+            crt: Grid::from_coords(
+                Coordinate::new(0, 0),
+                Coordinate::new(Self::WIDTH - 1, 5),
+                false,
+            ),
         }
     }
 
     fn execute(&mut self) {
-        match self.pending {
-            Some(Instruction::Addx(x)) => self.x += x,
-            _ => {}
+        if let Some(Instruction::Addx(x)) = self.pending {
+            self.x += x;
         }
 
         self.pending = None;
@@ -99,8 +102,9 @@ impl Cpu {
             self.until_execute = self.pending.unwrap().cycles();
         }
 
-        let x_pos = (self.current as i32 % Self::WIDTH as i32) - 1;
-        self.crt.push(self.x.abs_diff(x_pos) <= 1);
+        let pixel = self.current as i32 - 1;
+        let pos = Coordinate::new(pixel % Self::WIDTH, pixel / Self::WIDTH);
+        self.crt.set_resize(pos, self.x.abs_diff(pos.x) <= 1);
 
         // println!("START: {self}");
 
@@ -116,14 +120,10 @@ impl Cpu {
         Some(state)
     }
 
-    fn print(&self) -> String {
+    fn render(&self) -> String {
         self.crt
-            .iter()
-            .map(|c| if *c { "#" } else { "." })
-            .chunks(Self::WIDTH)
-            .into_iter()
-            .map(|mut c| c.join(""))
-            .join("\n")
+            .render(|lit| if *lit { '#' } else { '.' })
+            .to_string()
     }
 }
 
@@ -140,19 +140,12 @@ pub fn part_one(input: &str) -> Option<i32> {
     Some(sum)
 }
 
-pub fn part_two(input: &str) -> Option<i32> {
+pub fn part_two(input: &str) -> Option<String> {
     let mut cpu = Cpu::new(instructions(input));
 
     while cpu.cycle().is_some() {}
-    println!("{}", cpu.print());
 
-    None
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    Some(cpu.render().trim_end().to_string())
 }
 
 #[cfg(test)]
@@ -161,13 +154,33 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = crate::read_file("examples", 10);
         assert_eq!(part_one(&input), Some(13140));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_two(&input), None);
+        let input = crate::read_file("examples", 10);
+        let image = part_two(&input).unwrap();
+        assert_eq!(image.lines().count(), 6);
+        assert!(image.starts_with("##..##..##..##..##..##..##..##..##..##..\n"));
+        assert!(image.ends_with("#######.......#######.......#######....."));
+    }
+
+    #[test]
+    fn test_crt() {
+        let input = crate::read_file("examples", 10);
+        let mut cpu = Cpu::new(instructions(&input));
+        while cpu.cycle().is_some() {}
+
+        let expected = concat!(
+            "##..##..##..##..##..##..##..##..##..##..\n",
+            "###...###...###...###...###...###...###.\n",
+            "####....####....####....####....####....\n",
+            "#####.....#####.....#####.....#####.....\n",
+            "######......######......######......####\n",
+            "#######.......#######.......#######.....\n",
+        );
+        assert_eq!(cpu.render(), expected);
     }
 }
@@ -108,7 +108,7 @@ mod monkey {
 
     impl Test {
         fn throw(&self, item: u64) -> MonkeyToss {
-            if item % self.dividend == 0 {
+            if item.is_multiple_of(self.dividend) {
                 MonkeyToss {
                     to: self.throw_true as usize,
                     item,
@@ -230,7 +230,7 @@ mod monkey {
     }
 }
 
-use std::cell::RefCell;
+use std::{cell::RefCell, cmp::Reverse};
 
 use monkey::Monkey;
 
@@ -248,7 +248,7 @@ pub fn part_one(input: &str) -> Option<u64> {
         }
     }
 
-    monkeys.sort_by(|a, b| b.borrow().inspects.cmp(&a.borrow().inspects));
+    monkeys.sort_by_key(|m| Reverse(m.borrow().inspects));
     Some(
         monkeys
             .iter()
@@ -261,26 +261,20 @@ pub fn part_two(_input: &str) -> Option<u64> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = crate::read_file("examples", 11);
         assert_eq!(part_one(&input), Some(10605));
         // assert_eq!(part_one(&input), None);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = crate::read_file("examples", 11);
         assert_eq!(part_two(&input), None);
         // assert_eq!(part_two(&input), Some(2713310158));
     }
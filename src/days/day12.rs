@@ -0,0 +1,88 @@
+use aoc::{
+    grid::{Coordinate, Grid, DIRECTIONS},
+    search::bfs,
+};
+
+struct Map {
+    grid: Grid<u32>,
+    start: Coordinate,
+    end: Coordinate,
+}
+
+fn parse_map(input: &str) -> Map {
+    let (grid, markers) = Grid::parse(input, u32::MAX, &['S', 'E'], |c| {
+        let c = match c {
+            'S' => 'a',
+            'E' => 'z',
+            _ => c,
+        };
+
+        c as u32 - 'a' as u32
+    })
+    .unwrap();
+
+    Map {
+        grid,
+        start: markers.get('S').unwrap(),
+        end: markers.get('E').unwrap(),
+    }
+}
+
+impl Map {
+    /// Points that can be climbed to from `coord`, at most one higher
+    fn neighbors(&self, coord: Coordinate) -> Vec<Coordinate> {
+        let height = *self.grid.get(coord);
+        DIRECTIONS
+            .iter()
+            .map(|dir| coord.offset_direction(*dir, 1))
+            .filter(|new_coord| {
+                self.grid
+                    .get_bounded(*new_coord)
+                    .is_ok_and(|h| *h <= height + 1)
+            })
+            .collect()
+    }
+
+    fn find_shortest_path(&self, starts: &[Coordinate]) -> Option<u32> {
+        bfs(
+            starts.iter().copied(),
+            |c| self.neighbors(*c),
+            |c| *c == self.end,
+        )
+        .map(|path| path.cost as u32)
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let map = parse_map(input);
+    map.find_shortest_path(&[map.start])
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let map = parse_map(input);
+    let starts: Vec<_> = map
+        .grid
+        .coords()
+        .into_iter()
+        .filter(|c| *map.grid.get(*c) == 0)
+        .collect();
+
+    map.find_shortest_path(&starts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 12);
+        assert_eq!(part_one(&input), Some(31));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 12);
+        assert_eq!(part_two(&input), Some(29));
+    }
+}
@@ -17,7 +17,7 @@ enum Packet {
 }
 
 fn parse_int(i: &str) -> IResult<&str, Packet> {
-    map(nom::character::complete::u32, |i| Packet::Int(i))(i)
+    map(nom::character::complete::u32, Packet::Int)(i)
 }
 
 fn parse_list(i: &str) -> IResult<&str, Packet> {
@@ -146,25 +146,19 @@ pub fn part_two(input: &str) -> Option<u32> {
     )
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 13);
+        let input = crate::read_file("examples", 13);
         assert_eq!(part_one(&input), Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 13);
+        let input = crate::read_file("examples", 13);
         assert_eq!(part_two(&input), Some(140));
     }
 }
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use aoc::grid::{bounds, Coordinate, Grid, Line, OutOfBounds};
+use aoc::grid::{bounds, Coordinate, Grid, Line, OutOfBounds, StorageKind};
 
 mod parser {
     use aoc::grid::Coordinate;
@@ -24,14 +24,13 @@ mod parser {
 fn lines(input: &str) -> Vec<Line> {
     input
         .lines()
-        .map(|l| {
+        .flat_map(|l| {
             parser::coords(l)
                 .unwrap()
                 .windows(2)
-                .map(|w| Line::new(w[0].clone(), w[1].clone()))
+                .map(|w| Line::new(w[0], w[1]))
                 .collect::<Vec<_>>()
         })
-        .flatten()
         .collect()
 }
 
@@ -42,16 +41,22 @@ enum Tile {
     Sand,
 }
 
-impl Display for Tile {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl Tile {
+    fn symbol(&self) -> char {
         match self {
-            Tile::Empty => write!(f, "."),
-            Tile::Wall => write!(f, "#"),
-            Tile::Sand => write!(f, "o"),
+            Tile::Empty => '.',
+            Tile::Wall => '#',
+            Tile::Sand => 'o',
         }
     }
 }
 
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
 trait SandyCoord {
     fn diag_left(self) -> Self;
     fn diag_right(self) -> Self;
@@ -116,11 +121,8 @@ fn drop_sand(grid: &mut Grid<Tile>) -> Result<(), SandError> {
     Ok(())
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let coords: Vec<_> = lines(input)
-        .iter()
-        .flat_map(|line| line.clone().coords())
-        .collect();
+fn fill_abyss(input: &str) -> (Grid<Tile>, u32) {
+    let coords: Vec<_> = lines(input).iter().flat_map(|line| line.coords()).collect();
     let (min, max) = {
         let (mut min, mut max) = bounds(&coords).unwrap();
         min.x -= 1;
@@ -131,7 +133,7 @@ pub fn part_one(input: &str) -> Option<u32> {
         (min, max)
     };
 
-    let mut grid = Grid::from_coords(min, max, Tile::Empty);
+    let mut grid = Grid::with_storage(min, max, Tile::Empty, StorageKind::Dense);
     for coord in coords {
         grid.set_bounded(coord, Tile::Wall).unwrap();
     }
@@ -140,29 +142,33 @@ pub fn part_one(input: &str) -> Option<u32> {
         count += 1;
     }
 
+    (grid, count)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (_, count) = fill_abyss(input);
+
     Some(count)
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let coords: Vec<_> = lines(input)
-        .iter()
-        .flat_map(|line| line.clone().coords())
-        .collect();
+    let coords: Vec<_> = lines(input).iter().flat_map(|line| line.coords()).collect();
     let (min, max) = {
         let (mut min, mut max) = bounds(&coords).unwrap();
-        min.x -= 1000;
         min.y = 0;
-        max.x += 1000;
         max.y += 2;
 
         (min, max)
     };
 
-    let mut grid = Grid::from_coords(min, max, Tile::Empty);
+    let mut grid = Grid::with_storage(min, max, Tile::Empty, StorageKind::Dense);
+    // Sand piles up in a triangle, it can't spread further sideways than the floor is deep
+    grid.pad_x(max.y);
     for coord in coords {
         grid.set_bounded(coord, Tile::Wall).unwrap();
     }
-    for coord in Line::horizontal(max.y, min.x, max.x).coords() {
+    let floor = grid.bounds();
+    for coord in Line::horizontal(max.y, floor.min.x, floor.max.x).coords() {
         grid.set_bounded(coord, Tile::Wall).unwrap();
     }
     let mut count = 0;
@@ -173,25 +179,43 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(count + 1)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 14);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc::grid::Rect;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 14);
+        let input = crate::read_file("examples", 14);
         assert_eq!(part_one(&input), Some(24));
     }
 
+    #[test]
+    fn test_fill_abyss() {
+        let input = crate::read_file("examples", 14);
+        let (grid, _) = fill_abyss(&input);
+        let view = Rect::new(Coordinate::new(494, 0), Coordinate::new(503, 9));
+        let expected = concat!(
+            "..........\n",
+            "..........\n",
+            "......o...\n",
+            ".....ooo..\n",
+            "....#ooo##\n",
+            "...o#ooo#.\n",
+            "..###ooo#.\n",
+            "....oooo#.\n",
+            ".o.ooooo#.\n",
+            "#########.\n",
+        );
+        assert_eq!(
+            grid.render(Tile::symbol).viewport(view).to_string(),
+            expected
+        );
+    }
+
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 14);
+        let input = crate::read_file("examples", 14);
         assert_eq!(part_two(&input), Some(93));
     }
 }
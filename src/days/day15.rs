@@ -0,0 +1,149 @@
+use aoc::{
+    grid::{Coordinate, ManhattanBall},
+    interval::IntervalSet,
+};
+use itertools::Itertools;
+
+mod parser {
+    use aoc::grid::Coordinate;
+    use nom::{
+        bytes::complete::tag,
+        combinator::map,
+        error::VerboseError,
+        sequence::{preceded, separated_pair},
+    };
+
+    type IResult<'a, O> = nom::IResult<&'a str, O, VerboseError<&'a str>>;
+    pub type SensorBeacon = (Coordinate<i64>, Coordinate<i64>);
+
+    fn coord(i: &str) -> IResult<'_, Coordinate<i64>> {
+        use nom::character::complete::i64;
+        map(
+            separated_pair(
+                preceded(tag("x="), i64),
+                tag(", "),
+                preceded(tag("y="), i64),
+            ),
+            |(x, y)| Coordinate::new(x, y),
+        )(i)
+    }
+
+    fn sensor(i: &str) -> IResult<'_, Coordinate<i64>> {
+        preceded(tag("Sensor at "), coord)(i)
+    }
+
+    fn beacon(i: &str) -> IResult<'_, Coordinate<i64>> {
+        preceded(tag("closest beacon is at "), coord)(i)
+    }
+
+    pub fn sensor_beacon(i: &str) -> Result<SensorBeacon, nom::Err<VerboseError<&str>>> {
+        let (_, (sensor, beacon)) = separated_pair(sensor, tag(": "), beacon)(i)?;
+
+        Ok((sensor, beacon))
+    }
+}
+
+fn sensors_beacons(input: &str) -> Vec<parser::SensorBeacon> {
+    input
+        .lines()
+        .map(|line| parser::sensor_beacon(line).unwrap())
+        .collect()
+}
+
+fn sensor_balls(sb: &[parser::SensorBeacon]) -> Vec<ManhattanBall<i64>> {
+    sb.iter()
+        .map(|(s, b)| ManhattanBall::through(*s, *b))
+        .collect()
+}
+
+/// Cells on row `row` where the distress beacon can't be
+fn part_one_at(input: &str, row: i64) -> Option<u32> {
+    let sb = sensors_beacons(input);
+    let mut blocked: IntervalSet<i64> = sensor_balls(&sb)
+        .iter()
+        .filter_map(|ball| ball.row(row))
+        .collect();
+    for (_, beacon) in sb.iter().filter(|(_, b)| b.y == row) {
+        blocked.remove(beacon.x..=beacon.x);
+    }
+
+    Some(blocked.len() as u32)
+}
+
+/// First cell of `0..=limit` on row `y` that none of `balls` cover
+fn row_gap(balls: &[ManhattanBall<i64>], y: i64, limit: i64) -> Option<i64> {
+    let covered: IntervalSet<i64> = balls.iter().filter_map(|ball| ball.row(y)).collect();
+    covered.gaps(0..=limit).iter().next().map(|gap| *gap.start())
+}
+
+/// Free cells on the border of the area, columns are rows of the balls mirrored along x = y
+fn border_gaps(balls: &[ManhattanBall<i64>], limit: i64) -> Vec<Coordinate<i64>> {
+    let mirrored: Vec<_> = balls
+        .iter()
+        .map(|ball| ManhattanBall::new(Coordinate::new(ball.center.y, ball.center.x), ball.radius))
+        .collect();
+
+    [0, limit]
+        .into_iter()
+        .flat_map(|line| {
+            let in_row = row_gap(balls, line, limit).map(|x| Coordinate::new(x, line));
+            let in_column = row_gap(&mirrored, line, limit).map(|y| Coordinate::new(line, y));
+            in_row.into_iter().chain(in_column)
+        })
+        .collect()
+}
+
+/// Tuning frequency of the only free cell with both coordinates in `0..=limit`
+fn part_two_within(input: &str, limit: i64) -> Option<u64> {
+    let balls = sensor_balls(&sensors_beacons(input));
+    let in_area = |c: &Coordinate<i64>| (0..=limit).contains(&c.x) && (0..=limit).contains(&c.y);
+
+    // Inside the area, a single free cell is boxed in by the edges of the balls around it,
+    // so it has to be where two of those edges cross. On the border the area itself does
+    // part of the boxing, so the border lines are scanned instead
+    balls
+        .iter()
+        .tuple_combinations()
+        .flat_map(|(a, b)| a.expanded(1).edge_intersections(&b.expanded(1)))
+        .filter(in_area)
+        .chain(border_gaps(&balls, limit))
+        .find(|c| !balls.iter().any(|ball| ball.contains(*c)))
+        .map(|c| (c.x * 4_000_000 + c.y) as u64)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    part_one_at(input, 2_000_000)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    part_two_within(input, 4_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example asks about a much smaller area than the real input
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 15);
+        assert_eq!(part_one_at(&input, 10), Some(26));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 15);
+        assert_eq!(part_two_within(&input, 20), Some(56000011));
+    }
+
+    #[test]
+    fn test_part_two_border() {
+        // The only free cell is (4, 1), on the border but not where two edges cross
+        let input = "Sensor at x=-1, y=4: closest beacon is at x=-1, y=2\n\
+                     Sensor at x=2, y=3: closest beacon is at x=2, y=0\n\
+                     Sensor at x=5, y=0: closest beacon is at x=4, y=0\n\
+                     Sensor at x=0, y=-1: closest beacon is at x=4, y=-1";
+        assert_eq!(part_two_within(input, 4), Some(4 * 4_000_000 + 1));
+    }
+}
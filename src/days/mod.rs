@@ -0,0 +1,90 @@
+//! Registry of the solved days, so one binary can run any of them in process.
+//!
+//! A new day gets its own `dayNN` module with `part_one` and `part_two` functions, and a
+//! line in the [`register!`] call below.
+
+use std::{fmt::Display, ops::RangeInclusive};
+
+/// Every day of the calendar
+pub const DAYS: RangeInclusive<u8> = 1..=25;
+
+/// Both parts of a day's puzzle, answers rendered as text
+pub trait Solution: Sync {
+    fn part_one(&self, input: &str) -> Option<String>;
+    fn part_two(&self, input: &str) -> Option<String>;
+}
+
+/// A day module's `part_one` and `part_two` functions as a [`Solution`]
+struct Parts<A, B> {
+    one: fn(&str) -> Option<A>,
+    two: fn(&str) -> Option<B>,
+}
+
+impl<A: Display, B: Display> Solution for Parts<A, B> {
+    fn part_one(&self, input: &str) -> Option<String> {
+        (self.one)(input).map(|answer| answer.to_string())
+    }
+
+    fn part_two(&self, input: &str) -> Option<String> {
+        (self.two)(input).map(|answer| answer.to_string())
+    }
+}
+
+macro_rules! register {
+    ($($day:literal => $module:ident),* $(,)?) => {
+        $(mod $module;)*
+
+        static SOLUTIONS: &[(u8, &dyn Solution)] = &[
+            $(($day, &Parts { one: $module::part_one, two: $module::part_two }),)*
+        ];
+    };
+}
+
+register! {
+    1 => day01,
+    2 => day02,
+    3 => day03,
+    4 => day04,
+    5 => day05,
+    6 => day06,
+    7 => day07,
+    8 => day08,
+    9 => day09,
+    10 => day10,
+    11 => day11,
+    12 => day12,
+    13 => day13,
+    14 => day14,
+    15 => day15,
+}
+
+/// Solution of `day`, `None` when it isn't implemented yet
+pub fn get(day: u8) -> Option<&'static dyn Solution> {
+    SOLUTIONS
+        .iter()
+        .find(|(registered, _)| *registered == day)
+        .map(|(_, solution)| *solution)
+}
+
+/// Every day of the calendar with its solution, if it has one
+pub fn all() -> impl Iterator<Item = (u8, Option<&'static dyn Solution>)> {
+    DAYS.map(|day| (day, get(day)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        assert!(SOLUTIONS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(SOLUTIONS.iter().all(|(day, _)| DAYS.contains(day)));
+        assert_eq!(all().count(), 25);
+        assert!(get(26).is_none());
+
+        let input = crate::read_file("examples", 1);
+        let day = get(1).unwrap();
+        assert_eq!(day.part_one(&input).as_deref(), Some("24000"));
+        assert_eq!(day.part_two(&input).as_deref(), Some("45000"));
+    }
+}
@@ -2,45 +2,355 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::answers::{Answers, Verdict, ANSWERS_FILE};
+use advent_of_code::bench::{self, Bench};
+use advent_of_code::days::{self, Solution, DAYS};
+use advent_of_code::report::{self, Format, PartResult, Status};
+use advent_of_code::{format_part, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use rayon::prelude::*;
+use std::{
+    any::Any,
+    fmt::{self, Display, Write},
+    panic::{self, AssertUnwindSafe},
+    process,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug)]
+struct Args {
+    day: Option<u8>,
+    bench: bool,
+    format: Format,
+    record: bool,
+    verify: bool,
+    /// Days solved at the same time, 1 runs them one after another and 0 uses every core
+    jobs: usize,
+}
+
+/// Why the command line couldn't be read
+#[derive(Debug)]
+enum ArgsError {
+    Invalid(pico_args::Error),
+    /// Arguments that are neither a known flag nor the day, like a mistyped flag
+    Unknown(Vec<String>),
+    Day(String),
+}
+
+impl Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgsError::Invalid(e) => write!(f, "{}", e),
+            ArgsError::Unknown(args) => write!(f, "unknown argument: {}", args.join(" ")),
+            ArgsError::Day(day) => write!(f, "{:?} isn't a day", day),
+        }
+    }
+}
+
+impl From<pico_args::Error> for ArgsError {
+    fn from(e: pico_args::Error) -> Self {
+        ArgsError::Invalid(e)
+    }
+}
+
+fn parse_args(mut args: pico_args::Arguments) -> Result<Args, ArgsError> {
+    let mut parsed = Args {
+        day: None,
+        bench: args.contains("--bench"),
+        format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+        record: args.contains("--record"),
+        verify: args.contains("--verify"),
+        jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+    };
+
+    // Whatever is left is the day, anything else would be silently ignored otherwise
+    let (mut unknown, mut free): (Vec<String>, Vec<String>) = args
+        .finish()
+        .into_iter()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .partition(|arg| arg.starts_with('-'));
+    unknown.extend(free.drain(free.len().min(1)..));
+    if !unknown.is_empty() {
+        return Err(ArgsError::Unknown(unknown));
+    }
+    if let Some(day) = free.pop() {
+        parsed.day = Some(day.parse().map_err(|_| ArgsError::Day(day))?);
+    }
+
+    Ok(parsed)
+}
+
+/// Outcome of a day, with what it has to say when the output is text
+struct DayRun {
+    results: Vec<PartResult>,
+    text: String,
+}
+
+fn format_verdict(verdict: &Verdict) -> String {
+    match verdict {
+        Verdict::Pass => "✅ pass\n".to_string(),
+        Verdict::Fail { expected } => format!("❌ fail, expected {}\n", expected),
+        Verdict::Unknown => "❔ unknown\n".to_string(),
+    }
+}
+
+/// Message a panic was raised with
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => payload
+            .downcast_ref::<String>()
+            .map_or("unknown cause", String::as_str),
+    }
+}
+
+/// Runs one part and checks it against its recorded answer. With `--bench` the part is timed
+/// over many runs and its median time is kept. A panicking part is reported as such, without
+/// taking the rest of the run down with it.
+fn run_part(
+    (day, part): (u8, u8),
+    solver: impl Fn(&str) -> Option<String>,
+    input: &str,
+    answers: &Answers,
+    args: &Args,
+    text: &mut String,
+) -> PartResult {
+    let (answer, elapsed) =
+        bench::timed(|| panic::catch_unwind(AssertUnwindSafe(|| solver(input))));
+    let answer = match answer {
+        Ok(answer) => answer,
+        Err(payload) => {
+            if args.format == Format::Text {
+                writeln!(text, "🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET).unwrap();
+                writeln!(text, "💥 panicked: {}", panic_message(&*payload)).unwrap();
+            }
+            return PartResult {
+                elapsed: Some(elapsed),
+                verdict: answers.check(day, part, None),
+                ..PartResult::skipped(day, part, Status::Panicked)
+            };
+        }
+    };
+    let stats = (args.bench && answer.is_some()).then(|| Bench::new().run(|| solver(input)));
+    let verdict = answers.check(day, part, answer.as_deref());
+
+    if args.format == Format::Text {
+        text.push_str(&match stats {
+            Some(stats) => format_part(part, answer.as_deref(), stats),
+            None => format_part(
+                part,
+                answer.as_deref(),
+                format_args!("elapsed: {:.2?}", elapsed),
+            ),
+        });
+        text.push_str(&format_verdict(&verdict));
+    }
+
+    PartResult {
+        verdict,
+        ..PartResult::ran(day, part, answer, stats.map_or(elapsed, |s| s.median))
+    }
+}
+
+/// Runs both parts of `day`
+fn run_day(day: u8, solution: Option<&dyn Solution>, answers: &Answers, args: &Args) -> DayRun {
+    let mut text = String::new();
+    if args.format == Format::Text {
+        text.push_str("----------\n");
+        writeln!(text, "{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET).unwrap();
+        text.push_str("----------\n");
+    }
+    let skipped = |status, note: String, mut text: String| {
+        if args.format == Format::Text {
+            writeln!(text, "{}", note).unwrap();
+        }
+        let results = [1, 2]
+            .into_iter()
+            .map(|part| PartResult::skipped(day, part, status))
+            .collect();
+        DayRun { results, text }
+    };
+
+    let Some(solution) = solution else {
+        return skipped(Status::Unimplemented, "Not implemented.".to_string(), text);
+    };
+    let input = match advent_of_code::try_read_file("inputs", day) {
+        Ok(input) => input,
+        Err(e) => return skipped(Status::NoInput, format!("No input: {}", e), text),
+    };
+
+    let results = vec![
+        run_part(
+            (day, 1),
+            |i| solution.part_one(i),
+            &input,
+            answers,
+            args,
+            &mut text,
+        ),
+        run_part(
+            (day, 2),
+            |i| solution.part_two(i),
+            &input,
+            answers,
+            args,
+            &mut text,
+        ),
+    ];
+
+    DayRun { results, text }
+}
+
+/// Runs every day of the calendar, printing their text in day order.
+///
+/// One job runs the days one after another, each printed as soon as it's done, so their
+/// timings don't suffer from sharing the machine. More jobs solve that many days at once and
+/// print once all of them are done.
+fn run_all(answers: &Answers, args: &Args) -> Vec<PartResult> {
+    let days: Vec<_> = days::all().collect();
+    let runs: Vec<DayRun> = if args.jobs == 1 {
+        days.into_iter()
+            .map(|(day, solution)| {
+                let run = run_day(day, solution, answers, args);
+                print!("{}", run.text);
+                run
+            })
+            .collect()
+    } else {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(args.jobs)
+            .build()
+            .unwrap_or_else(|e| {
+                eprintln!("Failed to start {} jobs: {}", args.jobs, e);
+                process::exit(1);
+            });
+        let runs: Vec<DayRun> = pool.install(|| {
+            days.into_par_iter()
+                .map(|(day, solution)| run_day(day, solution, answers, args))
+                .collect()
+        });
+        for run in &runs {
+            print!("{}", run.text);
+        }
+        runs
+    };
+
+    runs.into_iter().flat_map(|run| run.results).collect()
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
-            );
+    let args = match parse_args(pico_args::Arguments::from_env()) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}. example: `cargo all --jobs 4 --format json`", e);
+            process::exit(1);
+        }
+    };
+    let mut answers = match Answers::load(ANSWERS_FILE) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", ANSWERS_FILE, e);
+            process::exit(1);
+        }
+    };
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    let timer = Instant::now();
+    let results: Vec<PartResult> = match args.day {
+        Some(day) if !DAYS.contains(&day) => {
+            eprintln!("There is no day {} in the calendar.", day);
+            process::exit(1);
+        }
+        Some(day) => {
+            let run = run_day(day, days::get(day), &answers, &args);
+            print!("{}", run.text);
+            run.results
+        }
+        None => run_all(&answers, &args),
+    };
+    let wall = timer.elapsed();
+
+    match args.format {
+        Format::Text if args.day.is_none() => {
+            let total: Duration = results.iter().filter_map(|r| r.elapsed).sum();
+            print!(
+                "{}Total:{} {}{:.2}ms",
+                ANSI_BOLD,
+                ANSI_RESET,
+                ANSI_ITALIC,
+                total.as_secs_f64() * 1000_f64
+            );
+            if args.jobs != 1 {
+                print!(" (wall: {:.2}ms)", wall.as_secs_f64() * 1000_f64);
             }
-        })
-        .sum();
+            println!("{}", ANSI_RESET);
+        }
+        Format::Text => {}
+        Format::Json => print!("{}", report::to_json(&results)),
+        Format::Csv => print!("{}", report::to_csv(&results)),
+    }
+
+    if args.record {
+        let solved: Vec<&PartResult> = results.iter().filter(|r| r.answer.is_some()).collect();
+        for result in &solved {
+            answers.record(result.day, result.part, result.answer.clone().unwrap());
+        }
+        if let Err(e) = answers.save(ANSWERS_FILE) {
+            eprintln!("Failed to write {}: {}", ANSWERS_FILE, e);
+            process::exit(1);
+        }
+        eprintln!("Recorded {} answers in {}.", solved.len(), ANSWERS_FILE);
+    }
+
+    let failed = results
+        .iter()
+        .filter(|r| r.verdict.is_fail() || r.status == Status::Panicked)
+        .count();
+    if args.verify && failed > 0 {
+        eprintln!("Parts not matching their recorded answers: {}", failed);
+        process::exit(1);
+    }
+}
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, ArgsError> {
+        parse_args(pico_args::Arguments::from_vec(
+            args.iter().map(Into::into).collect(),
+        ))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = parse(&[
+            "--bench", "--format", "json", "--record", "--verify", "--jobs", "4", "3",
+        ])
+        .unwrap();
+        assert_eq!(args.day, Some(3));
+        assert!(args.bench && args.record && args.verify);
+        assert_eq!(args.format, Format::Json);
+        assert_eq!(args.jobs, 4);
+
+        let args = parse(&[]).unwrap();
+        assert_eq!((args.day, args.jobs), (None, 1));
+    }
+
+    #[test]
+    fn test_unknown_args() {
+        for args in [&["--verfy"][..], &["3", "--verfy"], &["--verfy", "3"]] {
+            assert_eq!(
+                parse(args).unwrap_err().to_string(),
+                "unknown argument: --verfy"
+            );
+        }
+        assert_eq!(
+            parse(&["3", "4"]).unwrap_err().to_string(),
+            "unknown argument: 4"
+        );
+        assert_eq!(parse(&["x"]).unwrap_err().to_string(), "\"x\" isn't a day");
+        assert!(matches!(
+            parse(&["--jobs", "many"]),
+            Err(ArgsError::Invalid(_))
+        ));
+    }
 }
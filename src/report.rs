@@ -0,0 +1,240 @@
+//! Results of a run, in a shape other tools can read.
+
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+use crate::answers::Verdict;
+
+/// How a part's run went
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The solution found an answer
+    Solved,
+    /// The solution ran but came up with nothing
+    Unsolved,
+    /// The solution panicked
+    Panicked,
+    /// There's no solution for the day yet
+    Unimplemented,
+    /// The day's input file couldn't be read
+    NoInput,
+}
+
+impl Status {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Panicked => "panicked",
+            Status::Unimplemented => "unimplemented",
+            Status::NoInput => "no_input",
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Outcome of one part of one day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: Status,
+    /// Time the part took, `None` when it didn't run
+    pub elapsed: Option<Duration>,
+    /// Comparison with the recorded answer
+    pub verdict: Verdict,
+}
+
+impl PartResult {
+    /// Result of running a part, solved whenever there's an answer
+    pub fn ran(day: u8, part: u8, answer: Option<String>, elapsed: Duration) -> Self {
+        let status = match answer {
+            Some(_) => Status::Solved,
+            None => Status::Unsolved,
+        };
+        Self {
+            day,
+            part,
+            answer,
+            status,
+            elapsed: Some(elapsed),
+            verdict: Verdict::Unknown,
+        }
+    }
+
+    /// Result of a part that couldn't run
+    pub fn skipped(day: u8, part: u8, status: Status) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            status,
+            elapsed: None,
+            verdict: Verdict::Unknown,
+        }
+    }
+
+    fn nanos(&self) -> Option<u128> {
+        self.elapsed.map(|e| e.as_nanos())
+    }
+}
+
+/// How the runner writes its results
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// Decorated text printed as each part finishes
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownFormat(pub String);
+
+impl Display for UnknownFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown format {:?}, expected text, json or csv", self.0)
+    }
+}
+
+impl std::error::Error for UnknownFormat {}
+
+impl FromStr for Format {
+    type Err = UnknownFormat;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(UnknownFormat(s.to_string())),
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// JSON array with one object per part, `answer` and `ns` are null when missing
+pub fn to_json(results: &[PartResult]) -> String {
+    let objects: Vec<String> = results
+        .iter()
+        .map(|r| {
+            format!(
+                "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"status\": \"{}\", \"ns\": {}, \"verdict\": \"{}\"}}",
+                r.day,
+                r.part,
+                r.answer.as_deref().map_or("null".to_string(), json_string),
+                r.status,
+                r.nanos().map_or("null".to_string(), |ns| ns.to_string()),
+                r.verdict,
+            )
+        })
+        .collect();
+
+    if objects.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", objects.join(",\n"))
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// CSV with a header row and one row per part, missing values are left empty
+pub fn to_csv(results: &[PartResult]) -> String {
+    let mut out = String::from("day,part,answer,status,ns,verdict\n");
+    for r in results {
+        out.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            r.day,
+            r.part,
+            r.answer.as_deref().map_or(String::new(), csv_field),
+            r.status,
+            r.nanos().map_or(String::new(), |ns| ns.to_string()),
+            r.verdict,
+        ));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results() -> Vec<PartResult> {
+        let mut passed =
+            PartResult::ran(1, 1, Some("24000".to_string()), Duration::from_nanos(1500));
+        passed.verdict = Verdict::Pass;
+        vec![
+            passed,
+            PartResult::ran(1, 2, None, Duration::from_micros(2)),
+            PartResult::ran(10, 2, Some("#\"a,b\"\n.".to_string()), Duration::ZERO),
+            PartResult::skipped(16, 1, Status::Unimplemented),
+        ]
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!("JSON".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert_eq!(
+            "xml".parse::<Format>().unwrap_err().to_string(),
+            "unknown format \"xml\", expected text, json or csv"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            to_json(&results()),
+            "[\n  \
+             {\"day\": 1, \"part\": 1, \"answer\": \"24000\", \"status\": \"solved\", \"ns\": 1500, \"verdict\": \"pass\"},\n  \
+             {\"day\": 1, \"part\": 2, \"answer\": null, \"status\": \"unsolved\", \"ns\": 2000, \"verdict\": \"unknown\"},\n  \
+             {\"day\": 10, \"part\": 2, \"answer\": \"#\\\"a,b\\\"\\n.\", \"status\": \"solved\", \"ns\": 0, \"verdict\": \"unknown\"},\n  \
+             {\"day\": 16, \"part\": 1, \"answer\": null, \"status\": \"unimplemented\", \"ns\": null, \"verdict\": \"unknown\"}\n\
+             ]\n"
+        );
+        assert_eq!(to_json(&[]), "[]\n");
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            to_csv(&results()),
+            "day,part,answer,status,ns,verdict\n\
+             1,1,24000,solved,1500,pass\n\
+             1,2,,unsolved,2000,unknown\n\
+             10,2,\"#\"\"a,b\"\"\n.\",solved,0,unknown\n\
+             16,1,,unimplemented,,unknown\n"
+        );
+    }
+}